@@ -2,6 +2,7 @@
 //!
 //! Observability addon manages its own metrics configuration independently.
 
+use crate::tts::TtsDriverConfig;
 use serde::{Deserialize, Serialize};
 
 /// Metrics configuration for Prometheus endpoint.
@@ -15,6 +16,8 @@ pub struct MetricsConfig {
     pub token: Option<String>,
     #[serde(default = "default_healthz_path")]
     pub healthz_path: String,
+    #[serde(default)]
+    pub provider_health: ProviderHealthConfig,
 }
 
 impl Default for MetricsConfig {
@@ -24,6 +27,25 @@ impl Default for MetricsConfig {
             path: default_metrics_path(),
             token: None,
             healthz_path: default_healthz_path(),
+            provider_health: ProviderHealthConfig::default(),
+        }
+    }
+}
+
+/// Providers probed periodically and reported on the health endpoint.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ProviderHealthConfig {
+    #[serde(default = "default_provider_health_interval_secs")]
+    pub interval_secs: u64,
+    #[serde(default)]
+    pub tts: Vec<TtsDriverConfig>,
+}
+
+impl Default for ProviderHealthConfig {
+    fn default() -> Self {
+        Self {
+            interval_secs: default_provider_health_interval_secs(),
+            tts: Vec::new(),
         }
     }
 }
@@ -39,3 +61,7 @@ fn default_metrics_path() -> String {
 fn default_healthz_path() -> String {
     "/healthz".to_string()
 }
+
+fn default_provider_health_interval_secs() -> u64 {
+    60
+}
@@ -25,6 +25,14 @@
 //! path         = "/metrics"   # default
 //! healthz_path = "/healthz"   # default
 //! # token = "secret"          # optional bearer token guard
+//!
+//! # Optional provider reachability checks, reported under `providers`
+//! # on /healthz and as the `rustpbx_tts_provider_up` gauge.
+//! [metrics.provider_health]
+//! interval_secs = 60
+//! [[metrics.provider_health.tts]]
+//! type = "http"
+//! url  = "http://tts.internal/synthesize"
 //! ```
 
 use crate::addons::{Addon, AddonCategory};
@@ -199,6 +207,13 @@ impl Addon for ObservabilityAddon {
         let guard = self.config.read().await;
         let default_cfg = MetricsConfig::default();
         let cfg = guard.as_ref().unwrap_or(&default_cfg);
+        if !cfg.provider_health.tts.is_empty() {
+            crate::tts::health::spawn_health_checks(
+                cfg.provider_health.tts.clone(),
+                std::time::Duration::from_secs(cfg.provider_health.interval_secs.max(1)),
+                state.token().child_token(),
+            );
+        }
         tracing::info!(
             metrics_path = %cfg.path,
             healthz_path = %cfg.healthz_path,
@@ -245,7 +260,8 @@ impl Addon for ObservabilityAddon {
 ///
 /// Returns HTTP 200 with a JSON body.  Intentionally does **not** check the
 /// database or SIP server so it can be used as a pod-level liveness probe
-/// even when those services are temporarily unavailable.
+/// even when those services are temporarily unavailable. Provider check
+/// results are reported under `providers` but never change the status.
 async fn healthz_handler(State(state): State<AppState>) -> impl IntoResponse {
     let uptime_seconds = (chrono::Utc::now() - state.uptime).num_seconds();
     (
@@ -255,6 +271,7 @@ async fn healthz_handler(State(state): State<AppState>) -> impl IntoResponse {
             "uptime_seconds": uptime_seconds,
             "version": crate::version::get_short_version(),
             "active_calls": state.total_calls.load(std::sync::atomic::Ordering::Relaxed),
+            "providers": crate::tts::health::provider_health(),
         })),
     )
 }
@@ -23,6 +23,7 @@ use chrono::{Local, Utc};
 use sea_orm::{ActiveModelTrait, EntityTrait, Set};
 use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::{Duration as StdDuration, Instant};
@@ -49,6 +50,23 @@ pub struct TranscriptConfig {
     pub default_language: Option<String>,
     #[serde(default)]
     pub timeout_secs: Option<u64>,
+    /// Directory receiving a copy of the exact audio handed to the ASR
+    /// command, for debugging poor transcription accuracy.
+    #[serde(default)]
+    pub dump_dir: Option<String>,
+    /// Dumps kept in `dump_dir`; the oldest beyond this are deleted.
+    #[serde(default)]
+    pub dump_max_files: Option<usize>,
+    /// Total size of `dump_dir` in bytes before the oldest dumps are deleted.
+    #[serde(default)]
+    pub dump_max_bytes: Option<u64>,
+    /// Dumps older than this many seconds are deleted.
+    #[serde(default)]
+    pub dump_max_age_secs: Option<u64>,
+    /// Flag transcripts whose recognizer output holds no text as
+    /// `no_speech` so callers can tell silence from a missing transcript.
+    #[serde(default)]
+    pub report_no_speech: bool,
 }
 
 pub async fn get_call_record_transcript(
@@ -308,6 +326,26 @@ pub async fn trigger_call_record_transcript(
     }
     record.transcript_status = "processing".to_string();
     record.updated_at = now;
+
+    if let Some(dump_dir) = transcript_cfg
+        .dump_dir
+        .as_deref()
+        .map(str::trim)
+        .filter(|dir| !dir.is_empty())
+    {
+        match dump_transcript_audio(dump_dir, &record.call_id, &recording_path).await {
+            Ok(dump_path) => {
+                info!(call_id = %record.call_id, dump_path = %dump_path.display(), "Dumped ASR input audio");
+            }
+            Err(err) => {
+                warn!(call_id = %record.call_id, dump_dir = %dump_dir, "failed to dump ASR input audio: {}", err);
+            }
+        }
+        if let Err(err) = prune_transcript_dumps(dump_dir, &transcript_cfg).await {
+            warn!(dump_dir = %dump_dir, "failed to prune ASR dumps: {}", err);
+        }
+    }
+
     let mut cmd: tokio::process::Command = build_sensevoice_transcribe_command(
         &command,
         &recording_path,
@@ -466,48 +504,13 @@ pub async fn trigger_call_record_transcript(
         }
     };
 
-    // Convert CLI output to StoredTranscript
-    let mut segments = Vec::new();
-    let mut full_text = String::new();
-    let mut total_word_count = 0;
-
-    for channel in cli_output {
-        for segment in channel.segments {
-            let text = segment.text.trim();
-            if !text.is_empty() {
-                if !full_text.is_empty() {
-                    full_text.push(' ');
-                }
-                full_text.push_str(text);
-                total_word_count += text.split_whitespace().count();
-            }
-            segments.push(StoredTranscriptSegment {
-                idx: None,
-                text: text.to_string(),
-                start: segment.start_sec,
-                end: segment.end_sec,
-                channel: channel.channel,
-            });
-        }
+    let mut stored_transcript =
+        build_stored_transcript(cli_output, elapsed_secs, transcript_cfg.report_no_speech);
+    stored_transcript.metadata = call_metadata(record.metadata.as_ref());
+    if stored_transcript.no_speech {
+        info!(call_id = %record.call_id, "sensevoice-cli recognized no speech");
     }
 
-    let stored_transcript = StoredTranscript {
-        version: 1,
-        source: "sensevoice-cli".to_string(),
-        generated_at: Utc::now(),
-        language: None,
-        duration_secs: Some(elapsed_secs),
-        sample_rate: None,
-        segments,
-        text: full_text,
-        analysis: Some(StoredTranscriptAnalysis {
-            elapsed: Some(elapsed_secs),
-            rtf: None, // Calculate if needed
-            word_count: total_word_count,
-            asr_model: Some("sensevoice-small".to_string()),
-        }),
-    };
-
     // Save transcript
     if let Some(storage_ref) = storage.as_ref()
         && !storage_ref.is_local()
@@ -561,6 +564,67 @@ pub async fn trigger_call_record_transcript(
     .into_response()
 }
 
+/// Convert sensevoice-cli output into the stored transcript sidecar.
+fn build_stored_transcript(
+    cli_output: Vec<SenseVoiceCliChannel>,
+    elapsed_secs: f64,
+    report_no_speech: bool,
+) -> StoredTranscript {
+    let mut segments = Vec::new();
+    let mut full_text = String::new();
+    let mut total_word_count = 0;
+
+    for channel in cli_output {
+        for segment in channel.segments {
+            let text = segment.text.trim();
+            if !text.is_empty() {
+                if !full_text.is_empty() {
+                    full_text.push(' ');
+                }
+                full_text.push_str(text);
+                total_word_count += text.split_whitespace().count();
+            }
+            segments.push(StoredTranscriptSegment {
+                idx: None,
+                text: text.to_string(),
+                start: segment.start_sec,
+                end: segment.end_sec,
+                channel: channel.channel,
+            });
+        }
+    }
+
+    let no_speech = report_no_speech && full_text.is_empty();
+    StoredTranscript {
+        version: 1,
+        source: "sensevoice-cli".to_string(),
+        generated_at: Utc::now(),
+        language: None,
+        duration_secs: Some(elapsed_secs),
+        sample_rate: None,
+        segments,
+        text: full_text,
+        analysis: Some(StoredTranscriptAnalysis {
+            elapsed: Some(elapsed_secs),
+            rtf: None, // Calculate if needed
+            word_count: total_word_count,
+            asr_model: Some("sensevoice-small".to_string()),
+        }),
+        no_speech,
+        metadata: None,
+    }
+}
+
+/// String entries of the per-call metadata stored on the call record.
+fn call_metadata(value: Option<&Value>) -> Option<HashMap<String, String>> {
+    let map: HashMap<String, String> = value?
+        .as_object()?
+        .iter()
+        .filter_map(|(key, value)| Some((key.clone(), value.as_str()?.to_string())))
+        .collect();
+    (!map.is_empty()).then_some(map)
+}
+
 async fn load_stored_transcript(
     record: &CallRecordModel,
     cdr: Option<&CdrData>,
@@ -596,6 +660,78 @@ async fn load_stored_transcript(
     Ok(None)
 }
 
+/// Copy the audio file handed to the ASR command into `dump_dir`, keeping
+/// its extension so WAV inputs stay playable.
+async fn dump_transcript_audio(
+    dump_dir: &str,
+    call_id: &str,
+    audio_path: &str,
+) -> AnyResult<PathBuf> {
+    tokio::fs::create_dir_all(dump_dir)
+        .await
+        .with_context(|| format!("create dump directory {}", dump_dir))?;
+    let extension = std::path::Path::new(audio_path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or("raw");
+    let file_name = format!(
+        "{}_{}.{}",
+        call_id.replace(['/', '\\'], "_"),
+        Utc::now().timestamp_millis(),
+        extension
+    );
+    let dump_path = PathBuf::from(dump_dir).join(file_name);
+    tokio::fs::copy(audio_path, &dump_path)
+        .await
+        .with_context(|| format!("copy {} to {}", audio_path, dump_path.display()))?;
+    Ok(dump_path)
+}
+
+/// Enforce the dump retention limits on `dump_dir`, deleting the oldest
+/// files first. The newest dump is always kept. Returns the number removed.
+async fn prune_transcript_dumps(dump_dir: &str, cfg: &TranscriptConfig) -> AnyResult<usize> {
+    if cfg.dump_max_files.is_none()
+        && cfg.dump_max_bytes.is_none()
+        && cfg.dump_max_age_secs.is_none()
+    {
+        return Ok(0);
+    }
+
+    let mut dumps = Vec::new();
+    let mut entries = tokio::fs::read_dir(dump_dir)
+        .await
+        .with_context(|| format!("read dump directory {}", dump_dir))?;
+    while let Some(entry) = entries.next_entry().await? {
+        let meta = entry.metadata().await?;
+        if meta.is_file() {
+            let modified = meta.modified().unwrap_or(std::time::UNIX_EPOCH);
+            dumps.push((entry.path(), modified, meta.len()));
+        }
+    }
+    // Newest first
+    dumps.sort_by(|a, b| b.1.cmp(&a.1));
+
+    let now = std::time::SystemTime::now();
+    let max_age = cfg.dump_max_age_secs.map(StdDuration::from_secs);
+    let mut kept_bytes = 0u64;
+    let mut removed = 0;
+    for (index, (path, modified, len)) in dumps.into_iter().enumerate() {
+        let expired = max_age
+            .is_some_and(|max_age| now.duration_since(modified).is_ok_and(|age| age > max_age));
+        let over_count = cfg.dump_max_files.is_some_and(|max| index >= max);
+        let over_size = cfg.dump_max_bytes.is_some_and(|max| kept_bytes + len > max);
+        if index > 0 && (expired || over_count || over_size) {
+            tokio::fs::remove_file(&path)
+                .await
+                .with_context(|| format!("remove dump {}", path.display()))?;
+            removed += 1;
+        } else {
+            kept_bytes += len;
+        }
+    }
+    Ok(removed)
+}
+
 fn resolve_models_path(cfg: &TranscriptConfig) -> Option<String> {
     if let Ok(env_path) = std::env::var("MODEL_PATH") {
         let trimmed = env_path.trim();
@@ -738,6 +874,8 @@ fn build_transcript_payload_value(
             "segments": data.segments,
             "content": data.text,
             "analysis": data.analysis,
+            "no_speech": data.no_speech,
+            "metadata": data.metadata,
         })
     } else {
         json!({
@@ -1071,3 +1209,140 @@ fn persist_document(path: &str, content: String) -> Result<(), Box<Response>> {
         )),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_dump_transcript_audio_matches_input() {
+        let input = tempfile::NamedTempFile::with_suffix(".wav").unwrap();
+        let audio: Vec<u8> = (0..4096u32).map(|i| (i * 7 % 251) as u8).collect();
+        std::fs::write(input.path(), &audio).unwrap();
+
+        let dump_dir = tempfile::tempdir().unwrap();
+        let dump_path = dump_transcript_audio(
+            dump_dir.path().to_str().unwrap(),
+            "call/1",
+            input.path().to_str().unwrap(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(dump_path.parent().unwrap(), dump_dir.path());
+        assert_eq!(dump_path.extension().unwrap(), "wav");
+        assert!(
+            dump_path
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .starts_with("call_1_")
+        );
+        assert_eq!(std::fs::read(&dump_path).unwrap(), audio);
+    }
+
+    /// Write `count` dumps of `size` bytes, one minute apart, oldest first.
+    fn write_dumps(dir: &std::path::Path, count: usize, size: usize) -> Vec<PathBuf> {
+        let now = std::time::SystemTime::now();
+        (0..count)
+            .map(|i| {
+                let path = dir.join(format!("call_{}.wav", i));
+                std::fs::write(&path, vec![0u8; size]).unwrap();
+                let age = StdDuration::from_secs(60 * (count - i) as u64);
+                std::fs::File::options()
+                    .write(true)
+                    .open(&path)
+                    .unwrap()
+                    .set_modified(now - age)
+                    .unwrap();
+                path
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_prune_transcript_dumps_applies_retention() {
+        let dir = tempfile::tempdir().unwrap();
+        let dump_dir = dir.path().to_str().unwrap();
+
+        // Unlimited by default
+        write_dumps(dir.path(), 10, 100);
+        let cfg = TranscriptConfig::default();
+        assert_eq!(prune_transcript_dumps(dump_dir, &cfg).await.unwrap(), 0);
+
+        // Count: only the three newest survive
+        let paths = write_dumps(dir.path(), 10, 100);
+        let cfg = TranscriptConfig {
+            dump_max_files: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(prune_transcript_dumps(dump_dir, &cfg).await.unwrap(), 7);
+        assert!(paths[..7].iter().all(|p| !p.exists()));
+        assert!(paths[7..].iter().all(|p| p.exists()));
+
+        // Age: dumps written 1-10 minutes ago, keep the last 5 minutes
+        let paths = write_dumps(dir.path(), 10, 100);
+        let cfg = TranscriptConfig {
+            dump_max_age_secs: Some(5 * 60 + 30),
+            ..Default::default()
+        };
+        assert_eq!(prune_transcript_dumps(dump_dir, &cfg).await.unwrap(), 5);
+        assert!(paths[5..].iter().all(|p| p.exists()));
+
+        // Size: 250 bytes holds the two newest 100-byte dumps
+        let paths = write_dumps(dir.path(), 10, 100);
+        let cfg = TranscriptConfig {
+            dump_max_bytes: Some(250),
+            ..Default::default()
+        };
+        assert_eq!(prune_transcript_dumps(dump_dir, &cfg).await.unwrap(), 8);
+        assert!(paths[8..].iter().all(|p| p.exists()));
+    }
+
+    fn cli_channel(texts: &[&str]) -> SenseVoiceCliChannel {
+        serde_json::from_value(json!({
+            "channel": 0,
+            "segments": texts
+                .iter()
+                .enumerate()
+                .map(|(i, text)| json!({"start_sec": i as f64, "end_sec": i as f64 + 1.0, "text": text}))
+                .collect::<Vec<_>>(),
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_build_stored_transcript_reports_no_speech() {
+        let silent = build_stored_transcript(vec![cli_channel(&["", "  "])], 0.5, true);
+        assert!(silent.no_speech);
+        assert!(silent.text.is_empty());
+
+        let disabled = build_stored_transcript(vec![cli_channel(&[" "])], 0.5, false);
+        assert!(!disabled.no_speech);
+
+        let spoken = build_stored_transcript(vec![cli_channel(&["", "hello there"])], 0.5, true);
+        assert!(!spoken.no_speech);
+        assert_eq!(spoken.text, "hello there");
+        assert_eq!(spoken.analysis.unwrap().word_count, 2);
+    }
+
+    #[test]
+    fn test_call_metadata_is_copied_into_transcript() {
+        let record_metadata = json!({ "campaign_id": "spring-24", "agent_id": "a-7" });
+        let mut transcript = build_stored_transcript(vec![cli_channel(&["hi"])], 0.1, false);
+        transcript.metadata = call_metadata(Some(&record_metadata));
+
+        let stored = serde_json::to_value(&transcript).unwrap();
+        assert_eq!(stored["metadata"]["campaign_id"], "spring-24");
+        assert_eq!(stored["metadata"]["agent_id"], "a-7");
+
+        assert!(call_metadata(None).is_none());
+        assert!(call_metadata(Some(&json!({}))).is_none());
+        assert!(call_metadata(Some(&json!(["not", "a", "map"]))).is_none());
+
+        // A non-string value drops only that entry
+        let mixed = call_metadata(Some(&json!({ "agent_id": "a-7", "priority": 3 }))).unwrap();
+        assert_eq!(mixed.len(), 1);
+        assert_eq!(mixed["agent_id"], "a-7");
+    }
+}
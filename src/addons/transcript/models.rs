@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Debug, Clone, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
@@ -27,6 +28,12 @@ pub struct StoredTranscript {
     pub text: String,
     #[serde(default)]
     pub analysis: Option<StoredTranscriptAnalysis>,
+    /// Set when no-speech reporting is enabled and nothing was recognized.
+    #[serde(default)]
+    pub no_speech: bool,
+    /// Per-call metadata copied from the call record (campaign id, agent id, ...).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -389,6 +389,15 @@ impl AppStateBuilder {
             rwi_call_registry: None,
         });
 
+        if let (Some(rwi_config), Some(gateway)) = (config.rwi.as_ref(), core.rwi_gateway.clone()) {
+            if let Some(path) = rwi_config.event_capture_path.as_ref() {
+                let recorder = crate::rwi::replay::RwiEventRecorder::create(path).await?;
+                gateway.write().await.set_event_capture(recorder.spawn());
+                info!("recording RWI events to {}", path);
+            }
+            crate::rwi::RwiAddon::from_config(gateway, rwi_config).register();
+        }
+
         let sip_server = match self.proxy_builder {
             Some(builder) => builder.build().await,
             None => {
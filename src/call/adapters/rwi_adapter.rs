@@ -413,7 +413,8 @@ pub fn rwi_to_call_command(
         RwiCommandPayload::MediaStreamStart { .. }
         | RwiCommandPayload::MediaStreamStop { .. }
         | RwiCommandPayload::MediaInjectStart { .. }
-        | RwiCommandPayload::MediaInjectStop { .. } => Err(AdapterError::NotSupported(
+        | RwiCommandPayload::MediaInjectStop { .. }
+        | RwiCommandPayload::MediaInjectFormats { .. } => Err(AdapterError::NotSupported(
             "media streaming requires separate handling".to_string(),
         )
         .into()),
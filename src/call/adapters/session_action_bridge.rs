@@ -176,6 +176,10 @@ pub fn call_command_to_session_action(cmd: CallCommand) -> Result<SessionAction>
 
         CallCommand::StopPlayback { leg_id: _ } => Ok(SessionAction::StopPlayback),
 
+        CallCommand::InjectPcm { .. } => {
+            Err(AdapterError::NotSupported("pcm injection".to_string()).into())
+        }
+
         CallCommand::SendDtmf { .. } => {
             // DTMF sending is not directly supported in SessionAction
             Err(AdapterError::NotSupported("dtmf sending".to_string()).into())
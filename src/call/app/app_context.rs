@@ -19,6 +19,9 @@ pub struct CallInfo {
     pub direction: String,
     /// When the session started.
     pub started_at: DateTime<Utc>,
+    /// INVITE headers as `(name, value)` pairs, in message order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub invite_headers: Vec<(String, String)>,
 }
 
 pub struct AppSharedState {
@@ -148,6 +151,7 @@ mod tests {
             callee: "sip:bob@example.com".to_string(),
             direction: "inbound".to_string(),
             started_at: Utc::now(),
+            invite_headers: Vec::new(),
         }
     }
 
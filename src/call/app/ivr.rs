@@ -91,6 +91,12 @@ pub struct IvrApp {
     pending_unknown_digit: Option<String>,
     /// Optional TTS service synthesized from the IVR's own TTS config.
     tts_service: Option<Arc<crate::tts::TtsService>>,
+    /// Character budget of the call's TTS config, also applied to the
+    /// edge-cli fallback.
+    tts_budget: Option<usize>,
+    /// edge-cli service for `tts://` prompts when no TTS is configured, built
+    /// once so its character count spans the whole call.
+    fallback_tts_service: std::sync::OnceLock<Arc<crate::tts::TtsService>>,
 }
 
 impl IvrApp {
@@ -100,6 +106,10 @@ impl IvrApp {
             .tts
             .as_ref()
             .map(|cfg| Arc::new(crate::tts::TtsService::new(cfg.clone())));
+        let tts_budget = definition
+            .tts
+            .as_ref()
+            .and_then(|cfg| cfg.max_characters_per_call);
         Self {
             definition,
             state: IvrState::Init,
@@ -108,11 +118,16 @@ impl IvrApp {
             collected_variables: std::collections::HashMap::new(),
             pending_unknown_digit: None,
             tts_service,
+            tts_budget,
+            fallback_tts_service: std::sync::OnceLock::new(),
         }
     }
 
     /// Create a new `IvrApp` with an explicit TTS config override.
     pub fn with_tts(mut self, tts: Option<crate::tts::TtsConfig>) -> Self {
+        if let Some(cfg) = &tts {
+            self.tts_budget = cfg.max_characters_per_call;
+        }
         self.tts_service = tts.map(|cfg| Arc::new(crate::tts::TtsService::new(cfg)));
         self
     }
@@ -229,6 +244,39 @@ impl IvrApp {
             .unwrap_or_else(|| "root".to_string())
     }
 
+    /// The call's edge-cli fallback service, held to the call's budget.
+    pub(crate) fn fallback_tts_service(&self) -> &Arc<crate::tts::TtsService> {
+        self.fallback_tts_service.get_or_init(|| {
+            Arc::new(crate::tts::TtsService::new(crate::tts::TtsConfig {
+                cache_dir: std::env::temp_dir()
+                    .join("rustpbx_tts_cache")
+                    .to_string_lossy()
+                    .to_string(),
+                cache_ttl_seconds: 86400,
+                max_concurrency: None,
+                acquire_timeout_ms: None,
+                max_characters_per_call: self.tts_budget,
+                shared_cache: None,
+                warmup: false,
+                driver: crate::tts::TtsDriverConfig::Cli(crate::tts::CliTtsConfig {
+                    command: "edge-cli".to_string(),
+                    args: vec![
+                        "speak".to_string(),
+                        "--text".to_string(),
+                        "{text}".to_string(),
+                        "--voice".to_string(),
+                        "{voice}".to_string(),
+                        "--output".to_string(),
+                        "{output}".to_string(),
+                    ],
+                    output_format: "mp3".to_string(),
+                    timeout_seconds: 30,
+                }),
+                fallback: Vec::new(),
+            }))
+        })
+    }
+
     async fn resolve_audio(
         &self,
         file: Option<&str>,
@@ -259,27 +307,7 @@ impl IvrApp {
                 } else {
                     // Fallback: try edge-cli if available
                     let voice_str = tts_voice.unwrap_or("zh-CN-XiaoxiaoNeural").to_string();
-                    let fallback_cfg = crate::tts::TtsConfig {
-                        cache_dir: std::env::temp_dir()
-                            .join("rustpbx_tts_cache")
-                            .to_string_lossy()
-                            .to_string(),
-                        cache_ttl_seconds: 86400,
-                        driver: crate::tts::TtsDriverConfig::Cli(crate::tts::CliTtsConfig {
-                            command: "edge-cli".to_string(),
-                            args: vec![
-                                "speak".to_string(),
-                                "--text".to_string(),
-                                "{text}".to_string(),
-                                "--voice".to_string(),
-                                "{voice}".to_string(),
-                                "--output".to_string(),
-                                "{output}".to_string(),
-                            ],
-                            output_format: "mp3".to_string(),
-                        }),
-                    };
-                    let fallback_service = crate::tts::TtsService::new(fallback_cfg);
+                    let fallback_service = self.fallback_tts_service();
                     match fallback_service
                         .synthesize(&tts_text, Some(&voice_str))
                         .await
@@ -998,6 +1026,10 @@ impl CallApp for IvrApp {
         _ctx: &ApplicationContext,
     ) -> anyhow::Result<AppAction> {
         info!(ivr = %self.definition.name, "IVR application started");
+        if let Some(service) = self.tts_service.clone() {
+            // Overlaps with answering; the greeting is usually the first prompt
+            tokio::spawn(async move { service.warmup().await });
+        }
         ctrl.answer().await?;
 
         // Check business hours
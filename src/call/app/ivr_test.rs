@@ -2596,6 +2596,11 @@ action = { type = "transfer", target = "100" }
         let tts_config = TtsConfig {
             cache_dir: cache_dir.path().to_string_lossy().to_string(),
             cache_ttl_seconds: 3600,
+            max_concurrency: None,
+            acquire_timeout_ms: None,
+            max_characters_per_call: None,
+            shared_cache: None,
+            warmup: false,
             driver: TtsDriverConfig::Http(HttpTtsConfig {
                 url: format!("http://127.0.0.1:{}/tts", port),
                 method: "GET".to_string(),
@@ -2610,6 +2615,7 @@ action = { type = "transfer", target = "100" }
                 timeout_seconds: 5,
                 body_format: BodyFormat::Query,
             }),
+            fallback: Vec::new(),
         };
 
         let ivr = IvrDefinition {
@@ -2680,4 +2686,95 @@ action = { type = "transfer", target = "100" }
             )
             .await;
     }
+
+    #[tokio::test]
+    async fn test_ivr_tts_warmup_at_call_start() {
+        use crate::tts::{BodyFormat, HttpTtsConfig, TtsConfig, TtsDriverConfig};
+        use axum::{Router, http::Method, routing::get};
+        use std::collections::HashMap;
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let warmups = Arc::new(AtomicUsize::new(0));
+        let heads = warmups.clone();
+        let app = Router::new().route(
+            "/tts",
+            get(move |method: Method| {
+                if method == Method::HEAD {
+                    heads.fetch_add(1, Ordering::SeqCst);
+                }
+                std::future::ready("")
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.ok();
+        });
+
+        let cache_dir = tempfile::tempdir().unwrap();
+        let tts_config = TtsConfig {
+            cache_dir: cache_dir.path().to_string_lossy().to_string(),
+            cache_ttl_seconds: 3600,
+            max_concurrency: None,
+            acquire_timeout_ms: None,
+            max_characters_per_call: None,
+            shared_cache: None,
+            warmup: true,
+            driver: TtsDriverConfig::Http(HttpTtsConfig {
+                url: format!("http://127.0.0.1:{}/tts", port),
+                method: "GET".to_string(),
+                param_name: "text".to_string(),
+                extra_params: HashMap::new(),
+                headers: HashMap::new(),
+                output_format: "wav".to_string(),
+                timeout_seconds: 5,
+                body_format: BodyFormat::Query,
+            }),
+            fallback: Vec::new(),
+        };
+
+        let mut ivr = build_simple_ivr();
+        ivr.tts = Some(tts_config);
+        let mut stack = MockCallStack::run(Box::new(IvrApp::new(ivr)), "caller", "1000");
+
+        stack
+            .assert_cmd(200, "AcceptCall", |c| {
+                matches!(c, CallCommand::Answer { .. })
+            })
+            .await;
+
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(2);
+        while warmups.load(Ordering::SeqCst) == 0 && tokio::time::Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(warmups.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_ivr_edge_cli_fallback_keeps_call_budget() {
+        use crate::tts::{TtsBudgetExceeded, TtsConfig};
+
+        let mut ivr = build_simple_ivr();
+        let tts: TtsConfig = toml::from_str(
+            r#"
+            max_characters_per_call = 5
+            [driver]
+            type = "cli"
+            command = "true"
+            "#,
+        )
+        .unwrap();
+        ivr.tts = Some(tts);
+        // Without a TTS service, tts:// prompts go to the edge-cli fallback
+        let app = IvrApp::new(ivr).with_tts(None);
+
+        let fallback = app.fallback_tts_service();
+        assert!(std::sync::Arc::ptr_eq(fallback, app.fallback_tts_service()));
+        let err = fallback
+            .synthesize("longer than the call budget", None)
+            .await
+            .unwrap_err();
+        assert!(err.is::<TtsBudgetExceeded>(), "{err}");
+    }
 }
@@ -72,6 +72,7 @@ impl MockCallStack {
                 callee: callee.into(),
                 direction: "inbound".into(),
                 started_at: Utc::now(),
+                invite_headers: Vec::new(),
             },
             Arc::new(Config::default()),
         );
@@ -147,6 +147,18 @@ pub enum CallCommand {
         leg_id: Option<LegId>,
     },
 
+    /// Send caller-supplied PCM to a leg, replacing its output until flushed
+    InjectPcm {
+        /// Target leg (None = caller)
+        leg_id: Option<LegId>,
+        /// Mono 16-bit samples
+        pcm: Vec<i16>,
+        /// Sample rate of `pcm`
+        sample_rate: u32,
+        /// Send any buffered remainder and hand the output back to the peer
+        flush: bool,
+    },
+
     /// Send DTMF digits
     SendDtmf {
         /// Target leg
@@ -475,6 +487,7 @@ impl CallCommand {
         matches!(
             self,
             CallCommand::Play { .. }
+                | CallCommand::InjectPcm { .. }
                 | CallCommand::StartRecording { .. }
                 | CallCommand::SupervisorListen { .. }
                 | CallCommand::SupervisorWhisper { .. }
@@ -516,6 +529,10 @@ impl CallCommand {
             CallCommand::StopPlayback {
                 leg_id: Some(leg_id),
             } => Some(leg_id),
+            CallCommand::InjectPcm {
+                leg_id: Some(leg_id),
+                ..
+            } => Some(leg_id),
             CallCommand::SendDtmf { leg_id, .. } => Some(leg_id),
             CallCommand::SupervisorListen { supervisor_leg, .. } => Some(supervisor_leg),
             CallCommand::SupervisorWhisper { supervisor_leg, .. } => Some(supervisor_leg),
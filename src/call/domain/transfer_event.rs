@@ -29,3 +29,44 @@ pub struct ReferNotifyEvent {
     pub reason: Option<String>,
     pub event_type: ReferNotifyEventType,
 }
+
+/// Deliver `event` to every subscriber, dropping subscribers whose receiver
+/// has gone away so closed listeners are not fed for the rest of the process.
+pub fn broadcast_refer_event(subscribers: &mut Vec<ReferNotifyTx>, event: &ReferNotifyEvent) {
+    let before = subscribers.len();
+    subscribers.retain(|tx| tx.send(event.clone()).is_ok());
+    let pruned = before - subscribers.len();
+    if pruned > 0 {
+        tracing::debug!(
+            call_id = %event.call_id,
+            pruned,
+            "removed closed REFER NOTIFY subscribers"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_broadcast_prunes_closed_subscribers() {
+        let (live_tx, mut live_rx) = mpsc::unbounded_channel();
+        let (dead_tx, dead_rx) = mpsc::unbounded_channel();
+        drop(dead_rx);
+        let mut subscribers = vec![dead_tx, live_tx];
+
+        let event = ReferNotifyEvent {
+            call_id: "c1".to_string(),
+            sip_status: 200,
+            reason: None,
+            event_type: ReferNotifyEventType::Notify,
+        };
+        broadcast_refer_event(&mut subscribers, &event);
+
+        assert_eq!(subscribers.len(), 1);
+        let got = live_rx.try_recv().expect("live subscriber receives event");
+        assert_eq!(got.call_id, "c1");
+        assert_eq!(got.sip_status, 200);
+    }
+}
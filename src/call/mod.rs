@@ -620,6 +620,13 @@ pub struct CallRecordingConfig {
     pub option: Option<RecorderOption>,
     /// Auto start recording when call is answered
     pub auto_start: bool,
+    /// Pause the recorder (writing silence) while a leg holds the call via a
+    /// sendonly/recvonly/inactive re-INVITE; by default hold audio and MOH
+    /// are recorded like any other audio
+    pub pause_on_hold: bool,
+    /// Set when a per-call source (route result, dialplan hints) decided
+    /// `enabled`; the global `[recording]` policy then won't turn it back on.
+    pub overridden: bool,
 }
 
 impl CallRecordingConfig {
@@ -628,9 +635,17 @@ impl CallRecordingConfig {
             enabled: false,
             option: None,
             auto_start: true,
+            pause_on_hold: false,
+            overridden: false,
         }
     }
 
+    /// Per-call decision that supersedes the global recording policy.
+    pub fn set_override(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        self.overridden = true;
+    }
+
     pub fn enabled(mut self) -> Self {
         self.enabled = true;
         self
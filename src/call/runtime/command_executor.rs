@@ -157,6 +157,15 @@ impl ExecutionContext {
                     }
                 }
             }
+            CallCommand::InjectPcm { .. } => {
+                if self.media_profile.supports_media_injection {
+                    MediaCapabilityCheck::Allowed
+                } else {
+                    MediaCapabilityCheck::Denied {
+                        reason: "PCM injection not supported in bypass mode".to_string(),
+                    }
+                }
+            }
             CallCommand::Hold { music: Some(_), .. } => {
                 if self.media_profile.supports_media_injection {
                     MediaCapabilityCheck::Allowed
@@ -5,6 +5,7 @@
 
 use crate::call::domain::LegId;
 use crate::media::conference_mixer::{AudioFrame, ConferenceAudioMixer};
+use crate::media::mixer::LimiterMode;
 use anyhow::{Result, anyhow};
 use audio_codec::CodecType;
 use std::collections::HashMap;
@@ -169,6 +170,8 @@ pub struct ConferenceManager {
     participant_channels: Arc<RwLock<HashMap<LegId, ParticipantChannels>>>,
     /// Output receivers for local participants (mixed audio from conference)
     participant_output_rxs: Arc<RwLock<HashMap<LegId, mpsc::Receiver<AudioFrame>>>>,
+    /// Limiter used by every conference mixer this manager creates
+    limiter: LimiterMode,
 }
 
 impl ConferenceManager {
@@ -179,9 +182,16 @@ impl ConferenceManager {
             audio_mixers: Arc::new(RwLock::new(HashMap::new())),
             participant_channels: Arc::new(RwLock::new(HashMap::new())),
             participant_output_rxs: Arc::new(RwLock::new(HashMap::new())),
+            limiter: LimiterMode::default(),
         }
     }
 
+    /// Select the limiter applied to mixed audio in new conferences
+    pub fn with_limiter(mut self, limiter: LimiterMode) -> Self {
+        self.limiter = limiter;
+        self
+    }
+
     /// Create a new conference with in-server audio mixing
     pub async fn create_conference(
         &self,
@@ -199,7 +209,8 @@ impl ConferenceManager {
 
         // Create local audio mixer
         let mut audio_mixers = self.audio_mixers.write().await;
-        let mixer = Arc::new(ConferenceAudioMixer::new(conf_id.0.clone(), 8000));
+        let mixer =
+            Arc::new(ConferenceAudioMixer::new(conf_id.0.clone(), 8000).with_limiter(self.limiter));
         mixer.start();
         audio_mixers.insert(conf_id.clone(), mixer);
         info!(conf_id = %conf_id.0, "Conference created with local audio mixing");
@@ -480,6 +491,27 @@ mod tests {
         assert!(manager.get_conference(&conf_id).await.is_none());
     }
 
+    #[tokio::test]
+    async fn test_conference_uses_configured_limiter() {
+        let manager = ConferenceManager::new().with_limiter(LimiterMode::SoftKnee);
+        let conf_id = ConferenceId::from("limiter-conf");
+        manager
+            .create_conference(conf_id.clone(), None)
+            .await
+            .unwrap();
+
+        let mixer = manager
+            .audio_mixers
+            .read()
+            .await
+            .get(&conf_id)
+            .cloned()
+            .unwrap();
+        assert_eq!(mixer.limiter(), LimiterMode::SoftKnee);
+
+        manager.destroy_conference(&conf_id).await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_add_remove_participant_with_audio() {
         let manager = ConferenceManager::new();
@@ -364,8 +364,11 @@ fn global_factories() -> &'static ParkingRwLock<Vec<(&'static str, AppFactoryFn)
 }
 
 /// Register a custom call app factory that will be available to all sessions.
+/// Registering a name again replaces the earlier factory.
 pub fn register_call_app(name: &'static str, factory: AppFactoryFn) {
-    global_factories().write().push((name, factory));
+    let mut factories = global_factories().write();
+    factories.retain(|(existing, _)| *existing != name);
+    factories.push((name, factory));
 }
 
 /// Look up a custom call app from the global registry.
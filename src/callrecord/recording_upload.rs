@@ -112,10 +112,13 @@ impl RecordingUploadHook {
         let part = Part::bytes(data)
             .file_name(file_name)
             .mime_str("audio/wav")?;
-        let form = Form::new()
+        let mut form = Form::new()
             .text("call_id", record.call_id.clone())
-            .text("track_id", track_id.to_string())
-            .part("recording", part);
+            .text("track_id", track_id.to_string());
+        if let Some(metadata) = record.details.metadata.as_ref() {
+            form = form.text("metadata", serde_json::to_string(metadata)?);
+        }
+        let form = form.part("recording", part);
         let client = reqwest::Client::new();
         let mut request = client.post(&url).multipart(form);
         if let Some(headers) = self.policy.headers.as_ref() {
@@ -80,10 +80,22 @@ fn default_user_backends() -> Vec<UserBackendConfig> {
     vec![UserBackendConfig::default()]
 }
 
+fn default_locator_event_capacity() -> usize {
+    12
+}
+
 fn default_enable_latching() -> bool {
     true
 }
 
+fn default_rtp_dedup_window() -> u16 {
+    crate::media::bridge::DEFAULT_RTP_DEDUP_WINDOW
+}
+
+fn default_max_rtp_payload() -> usize {
+    crate::media::bridge::DEFAULT_MAX_RTP_PAYLOAD
+}
+
 fn default_generated_config_dir() -> String {
     "./config".to_string()
 }
@@ -143,6 +155,9 @@ pub struct RecordingPolicy {
     pub callee_deny: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub auto_start: Option<bool>,
+    /// Write silence instead of hold audio while a leg is on hold.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pause_on_hold: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filename_pattern: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -150,6 +165,8 @@ pub struct RecordingPolicy {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ptime: Option<u32>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub codec: Option<crate::media::recorder::RecordingCodec>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub path: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
@@ -176,7 +193,9 @@ impl RecordingPolicy {
         crate::call::CallRecordingConfig {
             enabled: self.enabled,
             auto_start: self.auto_start.unwrap_or(true),
+            pause_on_hold: self.pause_on_hold.unwrap_or(false),
             option: None,
+            overridden: false,
         }
     }
     pub fn recorder_path(&self) -> String {
@@ -229,6 +248,10 @@ pub struct Config {
     pub rtp_start_port: Option<u16>,
     #[serde(default = "default_config_rtp_end_port")]
     pub rtp_end_port: Option<u16>,
+    /// RTP ports reserved per call when estimating pool capacity. When set,
+    /// new calls are rejected with 503 once the pool would be exhausted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rtp_ports_per_call: Option<u16>,
 
     #[serde(default = "default_config_webrtc_start_port")]
     pub webrtc_port_start: Option<u16>,
@@ -595,6 +618,18 @@ pub struct RtpConfig {
     pub webrtc_start_port: Option<u16>,
     pub webrtc_end_port: Option<u16>,
     pub ice_servers: Option<Vec<IceServer>>,
+    pub ports_per_call: Option<u16>,
+}
+
+impl RtpConfig {
+    /// Number of concurrent calls the RTP port range can carry, or `None`
+    /// when admission control is not configured.
+    pub fn call_capacity(&self) -> Option<usize> {
+        let per_call = self.ports_per_call.filter(|n| *n > 0)?;
+        let (start, end) = (self.start_port?, self.end_port?);
+        let ports = end.saturating_sub(start) as usize + 1;
+        Some(ports / per_call as usize)
+    }
 }
 
 #[derive(Debug, Deserialize, Clone, Serialize)]
@@ -644,6 +679,10 @@ pub struct ProxyConfig {
     #[serde(default)]
     pub locator: LocatorConfig,
     pub locator_webhook: Option<LocatorWebhookConfig>,
+    /// Capacity of the locator event broadcast channel. Subscribers that fall
+    /// further behind than this skip events (reported as lagged). Default: 12.
+    #[serde(default = "default_locator_event_capacity")]
+    pub locator_event_capacity: usize,
     #[serde(default)]
     pub media_proxy: MediaProxyMode,
     pub codecs: Option<Vec<String>>,
@@ -671,6 +710,19 @@ pub struct ProxyConfig {
     pub queues_files: Vec<String>,
     #[serde(default = "default_enable_latching")]
     pub enable_latching: bool,
+    /// Recent RTP sequence numbers (up to 64) the media bridge remembers to
+    /// drop duplicated audio packets. `0` forwards duplicates.
+    #[serde(default = "default_rtp_dedup_window")]
+    pub rtp_dedup_window: u16,
+    /// Largest audio RTP payload in bytes the media bridge forwards. Larger
+    /// packets, or G.711/G.722/G.729 ones holding over 120ms, are dropped.
+    /// `0` disables the check.
+    #[serde(default = "default_max_rtp_payload")]
+    pub max_rtp_payload: usize,
+    /// How conference mixes that overflow 16-bit range are limited:
+    /// `hard_clip` (default) or `soft_knee`.
+    #[serde(default)]
+    pub conference_limiter: crate::media::mixer::LimiterMode,
     #[serde(default)]
     pub trunks: HashMap<String, TrunkConfig>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -979,9 +1031,13 @@ impl Default for ProxyConfig {
             registrar_expires: Some(60),
             ensure_user: Some(true),
             enable_latching: true,
+            rtp_dedup_window: default_rtp_dedup_window(),
+            max_rtp_payload: default_max_rtp_payload(),
+            conference_limiter: Default::default(),
             user_backends: default_user_backends(),
             locator: LocatorConfig::default(),
             locator_webhook: None,
+            locator_event_capacity: default_locator_event_capacity(),
             media_proxy: MediaProxyMode::default(),
             codecs: None,
             frequency_limiter: None,
@@ -1057,6 +1113,7 @@ impl Default for Config {
             external_ip: None,
             rtp_start_port: default_config_rtp_start_port(),
             rtp_end_port: default_config_rtp_end_port(),
+            rtp_ports_per_call: None,
             webrtc_port_start: default_config_webrtc_start_port(),
             webrtc_port_end: default_config_webrtc_end_port(),
             #[cfg(feature = "console")]
@@ -1107,6 +1164,7 @@ impl Config {
             webrtc_start_port: self.webrtc_port_start,
             webrtc_end_port: self.webrtc_port_end,
             ice_servers: self.ice_servers.clone(),
+            ports_per_call: self.rtp_ports_per_call,
         }
     }
 
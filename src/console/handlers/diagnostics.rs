@@ -657,6 +657,7 @@ fn trunk_config_from_model(model: &sip_trunk::Model) -> Option<routing::TrunkCon
         max_cps: model.max_cps.map(|value| value as u32),
         weight: None,
         transport: Some(model.sip_transport.as_str().to_string()),
+        next_hop_proxy: None,
         id: Some(model.id),
         direction: Some(model.direction.into()),
         inbound_hosts,
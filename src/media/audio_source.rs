@@ -4,7 +4,8 @@
 /// audio source switching without recreating PeerConnections or renegotiating SDP.
 ///
 /// # Supported Formats
-/// - **WAV files**: Using `hound` crate for reading PCM data
+/// - **WAV files**: Using `hound` crate for reading PCM data; μ-law/A-law
+///   (format 7/6) WAV files are decoded with the matching G.711 decoder
 /// - **MP3 files**: Using `minimp3` crate for decoding to PCM
 /// - **Raw audio**: PCMU, PCMA, G.722, G.729 encoded files
 ///
@@ -69,9 +70,21 @@ pub struct FileAudioSource {
     mp3_channels: u16,
     raw_file: Option<BufReader<File>>,
     raw_frame_size: usize,
+    /// Start of the encoded payload in `raw_file` (the data chunk for G.711 WAV).
+    raw_data_offset: u64,
+    /// Payload length when known (G.711 WAV); `None` reads to end of file.
+    raw_data_len: Option<u64>,
+    raw_data_remaining: Option<u64>,
     temp_file_path: Option<String>,
 }
 
+/// Location of the payload of a μ-law/A-law WAV file.
+struct G711WavLayout {
+    codec: CodecType,
+    data_offset: u64,
+    data_len: u64,
+}
+
 impl FileAudioSource {
     pub fn new(file_path: String, loop_playback: bool) -> Result<Self> {
         let (actual_path, temp_file_path) =
@@ -86,26 +99,55 @@ impl FileAudioSource {
                 (file_path.clone(), None)
             };
 
-        let codec_type = Self::detect_codec(&actual_path)?;
-        let decoder = create_decoder(codec_type);
-
         let extension = Path::new(&actual_path)
             .extension()
             .and_then(|s| s.to_str())
             .unwrap_or("")
             .to_lowercase();
+        // A recognised extension wins; otherwise (e.g. extension-less URLs or
+        // uploads) look at the file header before falling back to raw PCMU.
+        let extension = if Self::is_known_extension(&extension) {
+            extension
+        } else {
+            match Self::sniff_container(&actual_path) {
+                Some(container) => {
+                    debug!(file = %actual_path, container, "Detected audio container from header");
+                    container.to_string()
+                }
+                None => extension,
+            }
+        };
+
+        // hound only reads linear PCM, so G.711 WAVs go through the raw path.
+        let g711_wav = if extension == "wav" {
+            Self::probe_g711_wav(&actual_path)?
+        } else {
+            None
+        };
+
+        let codec_type = match &g711_wav {
+            Some(layout) => layout.codec,
+            None => Self::detect_codec(&extension)?,
+        };
+        let decoder = create_decoder(codec_type);
 
         // Detect actual MP3 metadata by pre-reading the first frame.
         let mut mp3_sample_rate = 44100u32;
         let mut mp3_channels = 2u16;
         let mut initial_mp3_buffer: Vec<i16> = Vec::new();
 
-        let (wav_reader, mp3_decoder, raw_file) = match extension.as_str() {
-            "wav" => {
-                let reader = hound::WavReader::open(&actual_path)?;
+        let (wav_reader, mp3_decoder, raw_file) = match (extension.as_str(), &g711_wav) {
+            ("wav", Some(layout)) => {
+                let mut file = File::open(&actual_path)?;
+                file.seek(SeekFrom::Start(layout.data_offset))?;
+                (None, None, Some(BufReader::new(file)))
+            }
+            ("wav", None) => {
+                let reader = hound::WavReader::open(&actual_path)
+                    .map_err(|e| anyhow!("invalid WAV file {}: {}", actual_path, e))?;
                 (Some(reader), None, None)
             }
-            "mp3" => {
+            ("mp3", _) => {
                 let file = File::open(&actual_path)?;
                 let buf_reader = BufReader::new(file);
                 let mut mp3_dec = minimp3::Decoder::new(buf_reader);
@@ -157,10 +199,142 @@ impl FileAudioSource {
             mp3_channels,
             raw_file,
             raw_frame_size,
+            raw_data_offset: g711_wav.as_ref().map(|l| l.data_offset).unwrap_or(0),
+            raw_data_len: g711_wav.as_ref().map(|l| l.data_len),
+            raw_data_remaining: g711_wav.as_ref().map(|l| l.data_len),
             temp_file_path,
         })
     }
 
+    /// Walk the RIFF chunks of a WAV file and return the payload layout if it
+    /// is mono μ-law (7) or A-law (6). Linear PCM returns `None` and is left to
+    /// hound. Truncated or malformed files and formats neither path can decode
+    /// are rejected with a descriptive error. Files without a RIFF/WAVE header
+    /// also return `None`.
+    fn probe_g711_wav(file_path: &str) -> Result<Option<G711WavLayout>> {
+        let file_len = std::fs::metadata(file_path)?.len();
+        let mut file = BufReader::new(File::open(file_path)?);
+        let mut riff = [0u8; 12];
+        if file.read_exact(&mut riff).is_err() || &riff[0..4] != b"RIFF" || &riff[8..12] != b"WAVE"
+        {
+            return Ok(None);
+        }
+
+        let mut offset = 12u64;
+        let mut codec = None;
+        let mut seen_fmt = false;
+        loop {
+            let mut chunk = [0u8; 8];
+            if file.read_exact(&mut chunk).is_err() {
+                return Err(anyhow!(
+                    "WAV file is truncated: no data chunk found: {}",
+                    file_path
+                ));
+            }
+            let size = u32::from_le_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]) as u64;
+            offset += 8;
+            match &chunk[0..4] {
+                b"fmt " => {
+                    if size < 16 {
+                        return Err(anyhow!(
+                            "WAV fmt chunk is {} bytes, expected at least 16: {}",
+                            size,
+                            file_path
+                        ));
+                    }
+                    let mut fmt = vec![0u8; size.min(64) as usize];
+                    file.read_exact(&mut fmt).map_err(|_| {
+                        anyhow!("WAV file is truncated in fmt chunk: {}", file_path)
+                    })?;
+                    let mut format_tag = u16::from_le_bytes([fmt[0], fmt[1]]);
+                    let channels = u16::from_le_bytes([fmt[2], fmt[3]]);
+                    let sample_rate = u32::from_le_bytes([fmt[4], fmt[5], fmt[6], fmt[7]]);
+                    let bits_per_sample = u16::from_le_bytes([fmt[14], fmt[15]]);
+                    // WAVE_FORMAT_EXTENSIBLE carries the real format in its sub-format GUID
+                    if format_tag == 0xFFFE && fmt.len() >= 26 {
+                        format_tag = u16::from_le_bytes([fmt[24], fmt[25]]);
+                    }
+                    if channels == 0 || sample_rate == 0 {
+                        return Err(anyhow!(
+                            "WAV header has {} channels at {} Hz: {}",
+                            channels,
+                            sample_rate,
+                            file_path
+                        ));
+                    }
+                    codec = match format_tag {
+                        7 => Some(CodecType::PCMU),
+                        6 => Some(CodecType::PCMA),
+                        // hound reads PCM of up to 16 bits as i16
+                        1 if bits_per_sample <= 16 => None,
+                        1 => {
+                            return Err(anyhow!(
+                                "{}-bit PCM WAV is not supported, expected 8 or 16 bits: {}",
+                                bits_per_sample,
+                                file_path
+                            ));
+                        }
+                        other => {
+                            return Err(anyhow!(
+                                "unsupported WAV format tag {:#06x}: {}",
+                                other,
+                                file_path
+                            ));
+                        }
+                    };
+                    if codec.is_some() && channels != 1 {
+                        return Err(anyhow!(
+                            "G.711 WAV with {} channels is not supported: {}",
+                            channels,
+                            file_path
+                        ));
+                    }
+                    // G.711 samples are decoded and paced at 8 kHz
+                    if codec.is_some() && sample_rate != 8000 {
+                        return Err(anyhow!(
+                            "G.711 WAV at {} Hz is not supported, expected 8000 Hz: {}",
+                            sample_rate,
+                            file_path
+                        ));
+                    }
+                    seen_fmt = true;
+                    file.seek(SeekFrom::Current(
+                        (size - fmt.len() as u64) as i64 + (size & 1) as i64,
+                    ))?;
+                }
+                b"data" => {
+                    if !seen_fmt {
+                        return Err(anyhow!("WAV data chunk precedes fmt chunk: {}", file_path));
+                    }
+                    let remaining = file_len.saturating_sub(offset);
+                    // Streaming writers leave 0 or 0xFFFFFFFF until the file is finalized
+                    let size = if size == 0 || size == u32::MAX as u64 {
+                        remaining
+                    } else {
+                        size
+                    };
+                    if size > remaining {
+                        return Err(anyhow!(
+                            "WAV file is truncated: data chunk declares {} bytes but only {} are present: {}",
+                            size,
+                            remaining,
+                            file_path
+                        ));
+                    }
+                    return Ok(codec.map(|codec| G711WavLayout {
+                        codec,
+                        data_offset: offset,
+                        data_len: size,
+                    }));
+                }
+                _ => {
+                    file.seek(SeekFrom::Current(size as i64 + (size & 1) as i64))?;
+                }
+            }
+            offset += size + (size & 1);
+        }
+    }
+
     /// Download file from HTTP URL to temporary location
     fn download_file(url: &str) -> Result<String> {
         let temp_dir = std::env::temp_dir();
@@ -196,13 +370,41 @@ impl FileAudioSource {
         Ok(temp_path.to_string_lossy().to_string())
     }
 
-    fn detect_codec(file_path: &str) -> Result<CodecType> {
-        let ext = Path::new(file_path)
-            .extension()
-            .and_then(|s| s.to_str())
-            .unwrap_or("");
+    fn is_known_extension(ext: &str) -> bool {
+        matches!(ext, "wav" | "mp3" | "u" | "ulaw" | "a" | "alaw")
+            || CodecType::try_from(ext).is_ok()
+    }
+
+    /// Identify WAV and MP3 files by their leading bytes.
+    fn sniff_container(file_path: &str) -> Option<&'static str> {
+        let mut header = [0u8; 12];
+        let mut file = File::open(file_path).ok()?;
+        let n = file.read(&mut header).ok()?;
+        let header = &header[..n];
+        if header.len() >= 12 && &header[0..4] == b"RIFF" && &header[8..12] == b"WAVE" {
+            Some("wav")
+        } else if header.starts_with(b"ID3") || Self::is_mpeg_frame_header(header) {
+            Some("mp3")
+        } else {
+            None
+        }
+    }
+
+    /// Frame sync alone also matches raw μ-law silence (`0xFF 0xFF ...`), so
+    /// require version, layer, bitrate and sample rate to be valid as well.
+    fn is_mpeg_frame_header(header: &[u8]) -> bool {
+        if header.len() < 4 || header[0] != 0xFF || header[1] & 0xE0 != 0xE0 {
+            return false;
+        }
+        let version = (header[1] >> 3) & 0x03;
+        let layer = (header[1] >> 1) & 0x03;
+        let bitrate = header[2] >> 4;
+        let sample_rate = (header[2] >> 2) & 0x03;
+        version != 0b01 && layer != 0b00 && bitrate != 0x0F && sample_rate != 0b11
+    }
 
-        match ext.to_lowercase().as_str() {
+    fn detect_codec(ext: &str) -> Result<CodecType> {
+        match ext {
             "wav" | "mp3" => Ok(CodecType::PCMU),
             _ => match CodecType::try_from(ext) {
                 Ok(codec) => Ok(codec),
@@ -288,9 +490,20 @@ impl AudioSource for FileAudioSource {
             }
             samples_read
         } else if let Some(ref mut reader) = self.raw_file {
-            let mut encoded_buf = vec![0u8; self.raw_frame_size];
+            let frame_size = match self.raw_data_remaining {
+                Some(remaining) => (remaining as usize).min(self.raw_frame_size),
+                None => self.raw_frame_size,
+            };
+            if frame_size == 0 {
+                self.eof_reached = true;
+                return 0;
+            }
+            let mut encoded_buf = vec![0u8; frame_size];
             match reader.read_exact(&mut encoded_buf) {
                 Ok(_) => {
+                    if let Some(remaining) = self.raw_data_remaining.as_mut() {
+                        *remaining -= frame_size as u64;
+                    }
                     let pcm = self.decoder.decode(&encoded_buf);
                     let copy_len = pcm.len().min(buffer.len());
                     buffer[..copy_len].copy_from_slice(&pcm[..copy_len]);
@@ -352,7 +565,8 @@ impl AudioSource for FileAudioSource {
             self.mp3_buffer.clear();
             self.mp3_buffer_pos = 0;
         } else if let Some(ref mut reader) = self.raw_file {
-            reader.seek(SeekFrom::Start(0))?;
+            reader.seek(SeekFrom::Start(self.raw_data_offset))?;
+            self.raw_data_remaining = self.raw_data_len;
         }
 
         Ok(())
@@ -578,7 +792,11 @@ pub fn estimate_audio_duration(file_path: &str) -> std::time::Duration {
 
     match ext.as_str() {
         "wav" => {
-            if let Ok(reader) = hound::WavReader::open(file_path) {
+            if let Ok(Some(layout)) = FileAudioSource::probe_g711_wav(file_path) {
+                // G.711 is 8000 Hz, one byte per sample.
+                let secs = layout.data_len as f64 / 8000.0;
+                std::time::Duration::from_secs_f64(secs.max(0.005))
+            } else if let Ok(reader) = hound::WavReader::open(file_path) {
                 let spec = reader.spec();
                 // `duration()` returns total frames (samples-per-channel), so
                 // dividing by sample_rate gives seconds.
@@ -666,6 +884,218 @@ mod tests {
         tmp
     }
 
+    /// Write a mono μ-law WAV (format 7) holding `samples` encoded with PCMU.
+    fn write_ulaw_wav(samples: &[i16]) -> NamedTempFile {
+        let tmp = NamedTempFile::with_suffix(".wav").expect("tempfile");
+        let payload = audio_codec::create_encoder(CodecType::PCMU).encode(samples);
+        let mut writer = crate::media::wav_writer::WavWriter::new(
+            tmp.reopen().expect("reopen"),
+            8000,
+            1,
+            Some(CodecType::PCMU),
+        );
+        writer.write_header_internal().expect("header");
+        writer.write_packet_internal(&payload).expect("payload");
+        writer.finalize_internal().expect("finalize");
+        tmp
+    }
+
+    // ── G.711 WAV ────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_file_source_decodes_ulaw_wav() {
+        let reference: Vec<i16> = (0..800)
+            .map(|i| {
+                let t = i as f64 / 8000.0;
+                ((2.0 * std::f64::consts::PI * 440.0 * t).sin() * 8000.0) as i16
+            })
+            .collect();
+        let tmp = write_ulaw_wav(&reference);
+        let path = tmp.path().to_string_lossy().to_string();
+
+        let mut source = FileAudioSource::new(path.clone(), false).expect("open μ-law wav");
+        assert_eq!(source.sample_rate(), 8000);
+        assert_eq!(source.channels(), 1);
+
+        let mut decoded = Vec::new();
+        let mut buffer = vec![0i16; 160];
+        loop {
+            let n = source.read_samples(&mut buffer);
+            if n == 0 {
+                break;
+            }
+            decoded.extend_from_slice(&buffer[..n]);
+        }
+        assert_eq!(
+            decoded.len(),
+            reference.len(),
+            "header must not be decoded as audio"
+        );
+        for (got, want) in decoded.iter().zip(&reference) {
+            // μ-law quantisation error stays within ~3% of full scale at this level.
+            assert!(
+                (*got as i32 - *want as i32).abs() <= 300,
+                "decoded {got} too far from reference {want}"
+            );
+        }
+
+        let duration = estimate_audio_duration(&path);
+        assert_eq!(duration.as_millis(), 100);
+    }
+
+    #[test]
+    fn test_file_source_reads_unfinalized_ulaw_wav() {
+        let samples = vec![1000i16; 320];
+        let payload = audio_codec::create_encoder(CodecType::PCMU).encode(&samples);
+        for placeholder in [0u32, u32::MAX] {
+            let tmp = NamedTempFile::with_suffix(".wav").expect("tempfile");
+            let mut writer = crate::media::wav_writer::WavWriter::new(
+                tmp.reopen().expect("reopen"),
+                8000,
+                1,
+                Some(CodecType::PCMU),
+            );
+            writer.write_header_internal().expect("header");
+            writer.write_packet_internal(&payload).expect("payload");
+            drop(writer);
+            // Never finalized: the header still carries the placeholder size
+            let mut bytes = std::fs::read(tmp.path()).expect("read");
+            let data_at = bytes
+                .windows(4)
+                .position(|w| w == b"data")
+                .expect("data chunk");
+            bytes[data_at + 4..data_at + 8].copy_from_slice(&placeholder.to_le_bytes());
+            std::fs::write(tmp.path(), &bytes).expect("write");
+
+            let mut source = FileAudioSource::new(tmp.path().to_string_lossy().to_string(), false)
+                .expect("open unfinalized wav");
+            let mut buffer = vec![0i16; 160];
+            let mut decoded = 0;
+            loop {
+                let n = source.read_samples(&mut buffer);
+                if n == 0 {
+                    break;
+                }
+                decoded += n;
+            }
+            assert_eq!(decoded, samples.len(), "size {placeholder:#x}");
+        }
+    }
+
+    #[test]
+    fn test_file_source_rejects_ulaw_wav_not_at_8khz() {
+        let samples = vec![1000i16; 320];
+        let payload = audio_codec::create_encoder(CodecType::PCMU).encode(&samples);
+        let tmp = NamedTempFile::with_suffix(".wav").expect("tempfile");
+        let mut writer = crate::media::wav_writer::WavWriter::new(
+            tmp.reopen().expect("reopen"),
+            16000,
+            1,
+            Some(CodecType::PCMU),
+        );
+        writer.write_header_internal().expect("header");
+        writer.write_packet_internal(&payload).expect("payload");
+        writer.finalize_internal().expect("finalize");
+        let path = tmp.path().to_string_lossy().to_string();
+
+        let err = FileAudioSource::new(path.clone(), false)
+            .err()
+            .expect("16 kHz G.711 WAV should be rejected");
+        assert!(err.to_string().contains("16000 Hz"), "{err}");
+        // The duration must not be estimated as if the data were 8 kHz
+        assert_ne!(
+            estimate_audio_duration(&path),
+            std::time::Duration::from_millis(40)
+        );
+    }
+
+    #[test]
+    fn test_file_source_sniffs_wav_without_extension() {
+        let samples: Vec<i16> = (0..320).map(|i| (i * 50) as i16).collect();
+        let wav = write_wav(16000, &samples);
+        let blob = NamedTempFile::with_suffix(".bin").expect("tempfile");
+        std::fs::copy(wav.path(), blob.path()).expect("copy");
+
+        let mut source =
+            FileAudioSource::new(blob.path().to_string_lossy().to_string(), false).expect("open");
+        assert_eq!(source.sample_rate(), 16000, "WAV header should be detected");
+        let mut buffer = vec![0i16; 320];
+        assert_eq!(source.read_samples(&mut buffer), 320);
+        assert_eq!(buffer, samples);
+    }
+
+    #[test]
+    fn test_file_source_raw_blob_falls_back_to_pcmu() {
+        let samples = vec![1000i16; 160];
+        let payload = audio_codec::create_encoder(CodecType::PCMU).encode(&samples);
+        let mut blob = NamedTempFile::with_suffix(".bin").expect("tempfile");
+        blob.write_all(&payload).expect("write");
+
+        let mut source =
+            FileAudioSource::new(blob.path().to_string_lossy().to_string(), false).expect("open");
+        assert_eq!(source.sample_rate(), 8000);
+        let mut buffer = vec![0i16; 160];
+        assert_eq!(source.read_samples(&mut buffer), 160);
+        assert!(buffer.iter().all(|s| (*s as i32 - 1000).abs() <= 64));
+    }
+
+    #[test]
+    fn test_file_source_silent_raw_pcmu_is_not_mp3() {
+        // μ-law silence is 0xFF, which carries an MPEG frame sync
+        let samples = vec![0i16; 320];
+        let payload = audio_codec::create_encoder(CodecType::PCMU).encode(&samples);
+        assert_eq!(payload[0], 0xFF);
+        let mut blob = NamedTempFile::with_suffix(".bin").expect("tempfile");
+        blob.write_all(&payload).expect("write");
+
+        let mut source =
+            FileAudioSource::new(blob.path().to_string_lossy().to_string(), false).expect("open");
+        assert_eq!(source.sample_rate(), 8000);
+        let mut buffer = vec![1i16; 320];
+        assert_eq!(source.read_samples(&mut buffer), 320);
+        assert!(buffer.iter().all(|s| s.abs() <= 8));
+    }
+
+    #[test]
+    fn test_file_source_rejects_truncated_wav() {
+        let wav = write_wav(8000, &[100i16; 800]);
+        let bytes = std::fs::read(wav.path()).expect("read");
+        let mut truncated = NamedTempFile::with_suffix(".wav").expect("tempfile");
+        truncated
+            .write_all(&bytes[..bytes.len() / 2])
+            .expect("write");
+
+        let err = FileAudioSource::new(truncated.path().to_string_lossy().to_string(), false)
+            .err()
+            .expect("truncated WAV must be rejected");
+        assert!(err.to_string().contains("truncated"), "{err}");
+    }
+
+    #[test]
+    fn test_file_source_rejects_unsupported_wav_format() {
+        let mut tmp = NamedTempFile::with_suffix(".wav").expect("tempfile");
+        {
+            let spec = hound::WavSpec {
+                channels: 1,
+                sample_rate: 8000,
+                bits_per_sample: 32,
+                sample_format: hound::SampleFormat::Float,
+            };
+            let mut writer =
+                hound::WavWriter::new(std::io::BufWriter::new(tmp.as_file_mut()), spec)
+                    .expect("WavWriter");
+            for _ in 0..160 {
+                writer.write_sample(0.5f32).expect("write_sample");
+            }
+            writer.finalize().expect("finalize");
+        }
+
+        let err = FileAudioSource::new(tmp.path().to_string_lossy().to_string(), false)
+            .err()
+            .expect("float WAV must be rejected");
+        assert!(err.to_string().contains("unsupported WAV format"), "{err}");
+    }
+
     // ── SilenceSource ────────────────────────────────────────────────────────
 
     #[test]
@@ -70,6 +70,18 @@ pub type PeerId = String;
 const BRIDGE_OUTPUT_PEER: u8 = 0;
 const BRIDGE_OUTPUT_FILE: u8 = 1;
 const BRIDGE_OUTPUT_MUTED: u8 = 2;
+const BRIDGE_OUTPUT_INJECT: u8 = 3;
+
+/// Default number of recent RTP sequence numbers remembered for duplicate
+/// detection.
+pub const DEFAULT_RTP_DEDUP_WINDOW: u16 = 64;
+
+/// Default upper bound on an audio RTP payload: an Ethernet MTU minus the
+/// IP, UDP and RTP headers.
+pub const DEFAULT_MAX_RTP_PAYLOAD: usize = 1460;
+
+/// Longest packetization time accepted for codecs with a fixed byte rate.
+const MAX_AUDIO_PTIME_MS: usize = 120;
 
 /// Atomic state for one endpoint's output mode + file source.
 /// Wrapped in a single Mutex to prevent TOCTOU between
@@ -77,12 +89,51 @@ const BRIDGE_OUTPUT_MUTED: u8 = 2;
 struct OutputState {
     mode: u8,
     file_source: Option<crate::media::FileTrackPlaybackSource>,
+    injector: Option<crate::media::pcm_inject::PcmInjector>,
     next_rtp_timestamp: Option<u32>,
     next_sequence_number: Option<u16>,
     active_rtp_offset: Option<u32>,
     active_seq_offset: Option<u16>,
 }
 
+impl OutputState {
+    /// Rewrite `frame` onto this endpoint's continuous output timeline so a
+    /// new source picks up the sequence number and RTP timestamp where the
+    /// previous one left off.
+    fn map_to_output_timeline(&mut self, frame: &mut AudioFrame) {
+        let src_seq = frame.sequence_number.unwrap_or_default();
+        let src_ts = frame.rtp_timestamp;
+
+        let seq_offset = match self.active_seq_offset {
+            Some(offset) => offset,
+            None => {
+                let expected = self.next_sequence_number.unwrap_or(src_seq);
+                let offset = expected.wrapping_sub(src_seq);
+                self.active_seq_offset = Some(offset);
+                offset
+            }
+        };
+
+        let ts_offset = match self.active_rtp_offset {
+            Some(offset) => offset,
+            None => {
+                let expected = self.next_rtp_timestamp.unwrap_or(src_ts);
+                let offset = expected.wrapping_sub(src_ts);
+                self.active_rtp_offset = Some(offset);
+                offset
+            }
+        };
+
+        let mapped_seq = src_seq.wrapping_add(seq_offset);
+        let mapped_ts = src_ts.wrapping_add(ts_offset);
+        frame.sequence_number = Some(mapped_seq);
+        frame.rtp_timestamp = mapped_ts;
+
+        self.next_sequence_number = Some(mapped_seq.wrapping_add(1));
+        self.next_rtp_timestamp = Some(mapped_ts.wrapping_add(frame_ticks_20ms(frame.clock_rate)));
+    }
+}
+
 /// One peer's media endpoint within an N-peer bridge.
 pub struct PeerEntry {
     /// PeerConnection for this peer
@@ -119,6 +170,10 @@ struct LegStats {
     lost: AtomicU64,
     /// Packets dropped due to send-channel errors
     dropped: AtomicU64,
+    /// Duplicate (retransmitted) packets discarded before forwarding
+    duplicates: AtomicU64,
+    /// Audio packets discarded for an implausible payload size
+    rejected: AtomicU64,
 }
 
 impl LegStats {
@@ -128,10 +183,104 @@ impl LegStats {
             bytes: AtomicU64::new(0),
             lost: AtomicU64::new(0),
             dropped: AtomicU64::new(0),
+            duplicates: AtomicU64::new(0),
+            rejected: AtomicU64::new(0),
         })
     }
 }
 
+/// RTP timestamp distance from the newest packet beyond which a packet is
+/// treated as a new stream: 10 s at 48 kHz, more than a full window of
+/// 120 ms packets.
+const RTP_DEDUP_MAX_TIMESTAMP_JUMP: u32 = 480_000;
+
+/// Sliding-window duplicate detector over RTP sequence numbers, in the style
+/// of an SRTP replay window. A new SSRC, a large timestamp jump, or a
+/// sequence jump further than the window in either direction starts a fresh
+/// window so stream restarts are never mistaken for duplicates. The
+/// timestamp check covers restarts when the SSRC is not available.
+struct RtpDeduplicator {
+    /// Window size in packets (at most 64); `0` disables detection
+    window: u32,
+    ssrc: Option<u32>,
+    highest: Option<u16>,
+    /// RTP timestamp of the packet at `highest`
+    highest_timestamp: u32,
+    /// Bit `n` set means `highest - n` has been seen
+    seen: u64,
+}
+
+impl RtpDeduplicator {
+    fn new(window: u16) -> Self {
+        Self {
+            window: u32::from(window.min(64)),
+            ssrc: None,
+            highest: None,
+            highest_timestamp: 0,
+            seen: 0,
+        }
+    }
+
+    /// Returns `true` when `seq` was already seen within the window.
+    fn is_duplicate(&mut self, seq: u16, ssrc: Option<u32>, timestamp: u32) -> bool {
+        if self.window == 0 {
+            return false;
+        }
+        if ssrc.is_some() && ssrc != self.ssrc {
+            self.ssrc = ssrc;
+            self.highest = None;
+        }
+        let Some(highest) = self.highest else {
+            self.restart(seq, timestamp);
+            return false;
+        };
+        let delta = seq.wrapping_sub(highest) as i16;
+        let distance = u32::from(delta.unsigned_abs());
+        let ts_jump = (timestamp.wrapping_sub(self.highest_timestamp) as i32).unsigned_abs();
+        if distance >= self.window || ts_jump > RTP_DEDUP_MAX_TIMESTAMP_JUMP {
+            self.restart(seq, timestamp);
+            return false;
+        }
+        if delta > 0 {
+            self.seen = (self.seen << distance) | 1;
+            self.highest = Some(seq);
+            self.highest_timestamp = timestamp;
+            return false;
+        }
+        let bit = 1u64 << distance;
+        if self.seen & bit != 0 {
+            return true;
+        }
+        self.seen |= bit;
+        false
+    }
+
+    fn restart(&mut self, seq: u16, timestamp: u32) {
+        self.highest = Some(seq);
+        self.highest_timestamp = timestamp;
+        self.seen = 1;
+    }
+}
+
+/// Whether an audio payload of `len` bytes is plausible for its payload type.
+/// G.711, G.722 and G.729 are also bounded by `MAX_AUDIO_PTIME_MS` of audio at
+/// their fixed byte rate; other payload types only by `max_payload`.
+/// `max_payload == 0` disables the check.
+fn audio_payload_plausible(payload_type: Option<u8>, len: usize, max_payload: usize) -> bool {
+    if max_payload == 0 {
+        return true;
+    }
+    let bytes_per_ms = match payload_type {
+        Some(0 | 8 | 9) => Some(8),
+        Some(18) => Some(1),
+        _ => None,
+    };
+    let limit = bytes_per_ms.map_or(max_payload, |rate| {
+        max_payload.min(rate * MAX_AUDIO_PTIME_MS)
+    });
+    len <= limit
+}
+
 struct VideoForwardingTrack {
     id: String,
     inner: Arc<dyn MediaStreamTrack>,
@@ -276,6 +425,7 @@ fn output_mode_name(mode: u8) -> &'static str {
         BRIDGE_OUTPUT_PEER => "peer",
         BRIDGE_OUTPUT_FILE => "file",
         BRIDGE_OUTPUT_MUTED => "muted",
+        BRIDGE_OUTPUT_INJECT => "inject",
         _ => "unknown",
     }
 }
@@ -308,6 +458,10 @@ pub struct BridgePeer {
     forwarding_started: AtomicBool,
     /// Shared recorder for call recording (written by both bridge directions)
     recorder: Option<Arc<parking_lot::RwLock<Option<Recorder>>>>,
+    /// Recent sequence numbers checked for duplicate audio packets; `0` disables
+    rtp_dedup_window: u16,
+    /// Largest audio payload forwarded, in bytes; `0` disables the check
+    max_rtp_payload: usize,
     dtmf_sink: Arc<parking_lot::RwLock<Option<BridgeDtmfSink>>>,
     /// Audio sender channels for forwarding — fast-path aliases
     webrtc_send: Arc<AsyncMutex<Option<MediaSender>>>,
@@ -367,12 +521,15 @@ impl BridgePeer {
             cancel_token: CancellationToken::new(),
             forwarding_started: AtomicBool::new(false),
             recorder: None,
+            rtp_dedup_window: DEFAULT_RTP_DEDUP_WINDOW,
+            max_rtp_payload: DEFAULT_MAX_RTP_PAYLOAD,
             dtmf_sink: Arc::new(parking_lot::RwLock::new(None)),
             webrtc_send: Arc::new(AsyncMutex::new(None)),
             rtp_send: Arc::new(AsyncMutex::new(None)),
             webrtc_output_state: Arc::new(AsyncMutex::new(OutputState {
                 mode: BRIDGE_OUTPUT_PEER,
                 file_source: None,
+                injector: None,
                 next_rtp_timestamp: None,
                 next_sequence_number: None,
                 active_rtp_offset: None,
@@ -381,6 +538,7 @@ impl BridgePeer {
             rtp_output_state: Arc::new(AsyncMutex::new(OutputState {
                 mode: BRIDGE_OUTPUT_PEER,
                 file_source: None,
+                injector: None,
                 next_rtp_timestamp: None,
                 next_sequence_number: None,
                 active_rtp_offset: None,
@@ -615,10 +773,14 @@ impl BridgePeer {
                             let w_bytes = w2r.bytes.load(Ordering::Relaxed);
                             let w_lost  = w2r.lost.load(Ordering::Relaxed);
                             let w_drop  = w2r.dropped.load(Ordering::Relaxed);
+                            let w_dup   = w2r.duplicates.load(Ordering::Relaxed);
+                            let w_rej   = w2r.rejected.load(Ordering::Relaxed);
                             let r_pkts  = r2w.packets.load(Ordering::Relaxed);
                             let r_bytes = r2w.bytes.load(Ordering::Relaxed);
                             let r_lost  = r2w.lost.load(Ordering::Relaxed);
                             let r_drop  = r2w.dropped.load(Ordering::Relaxed);
+                            let r_dup   = r2w.duplicates.load(Ordering::Relaxed);
+                            let r_rej   = r2w.rejected.load(Ordering::Relaxed);
 
                             let dw_pkts  = w_pkts.saturating_sub(prev_w_pkts);
                             let dw_bytes = w_bytes.saturating_sub(prev_w_bytes);
@@ -640,10 +802,14 @@ impl BridgePeer {
                                 webrtc_to_rtp_kbps  = dw_bytes * 8 / 5 / 1000,
                                 webrtc_to_rtp_loss  = format!("{:.2}%", w_loss_pct),
                                 webrtc_to_rtp_drop  = w_drop,
+                                webrtc_to_rtp_dup   = w_dup,
+                                webrtc_to_rtp_rej   = w_rej,
                                 rtp_to_webrtc_pps   = dr_pkts,
                                 rtp_to_webrtc_kbps  = dr_bytes * 8 / 5 / 1000,
                                 rtp_to_webrtc_loss  = format!("{:.2}%", r_loss_pct),
                                 rtp_to_webrtc_drop  = r_drop,
+                                rtp_to_webrtc_dup   = r_dup,
+                                rtp_to_webrtc_rej   = r_rej,
                                 "Bridge leg stats [5s]"
                             );
 
@@ -781,6 +947,73 @@ impl BridgePeer {
         );
     }
 
+    /// Encode `pcm` with `codec_info` and send it on `endpoint`'s output,
+    /// continuing the endpoint's sequence and timestamp timeline the same way
+    /// file playback does. Peer media is suppressed until a call with `flush`
+    /// sends the buffered remainder and hands the output back to the peer.
+    /// A flush with no injection running leaves the output untouched.
+    /// Returns the number of frames sent.
+    pub async fn inject_pcm(
+        &self,
+        endpoint: BridgeEndpoint,
+        codec_info: crate::media::negotiate::CodecInfo,
+        pcm: &[i16],
+        sample_rate: u32,
+        flush: bool,
+    ) -> Result<usize> {
+        let sender = match endpoint {
+            BridgeEndpoint::WebRtc => self.get_webrtc_sender().await,
+            BridgeEndpoint::Rtp => self.get_rtp_sender().await,
+        }
+        .ok_or_else(|| anyhow::anyhow!("bridge {:?} output sender is not ready", endpoint))?;
+
+        let mut state = self.output_state(endpoint).lock().await;
+        let injecting = state.mode == BRIDGE_OUTPUT_INJECT && state.injector.is_some();
+        if flush && pcm.is_empty() && !injecting {
+            return Ok(0);
+        }
+        let old_source = if !injecting {
+            state.injector = Some(crate::media::pcm_inject::PcmInjector::new(codec_info));
+            state.mode = BRIDGE_OUTPUT_INJECT;
+            state.active_rtp_offset = None;
+            state.active_seq_offset = None;
+            self.output_mode(endpoint)
+                .store(BRIDGE_OUTPUT_INJECT, Ordering::Release);
+            state.file_source.take()
+        } else {
+            None
+        };
+        let injector = state.injector.as_mut().expect("injector installed above");
+        let mut samples = injector.push(pcm, sample_rate)?;
+        if flush {
+            samples.extend(injector.flush());
+        }
+        for sample in samples.iter_mut() {
+            if let MediaSample::Audio(frame) = sample {
+                state.map_to_output_timeline(frame);
+            }
+        }
+        if flush {
+            state.injector = None;
+            state.mode = BRIDGE_OUTPUT_PEER;
+            state.active_rtp_offset = None;
+            state.active_seq_offset = None;
+            self.output_mode(endpoint)
+                .store(BRIDGE_OUTPUT_PEER, Ordering::Release);
+        }
+        drop(state);
+        drop(old_source);
+
+        let sent = samples.len();
+        for sample in samples {
+            sender
+                .send(sample)
+                .await
+                .map_err(|e| anyhow::anyhow!("failed to send injected audio: {}", e))?;
+        }
+        Ok(sent)
+    }
+
     pub async fn mute_output(&self, endpoint: BridgeEndpoint) {
         let mut state = self.output_state(endpoint).lock().await;
         state.mode = BRIDGE_OUTPUT_MUTED;
@@ -944,39 +1177,7 @@ impl BridgePeer {
                         };
 
                         if let MediaSample::Audio(frame) = &mut sample {
-                            let mut guard = output_state.lock().await;
-
-                            let src_seq = frame.sequence_number.unwrap_or_default();
-                            let src_ts = frame.rtp_timestamp;
-
-                            let seq_offset = match guard.active_seq_offset {
-                                Some(offset) => offset,
-                                None => {
-                                    let expected = guard.next_sequence_number.unwrap_or(src_seq);
-                                    let offset = expected.wrapping_sub(src_seq);
-                                    guard.active_seq_offset = Some(offset);
-                                    offset
-                                }
-                            };
-
-                            let ts_offset = match guard.active_rtp_offset {
-                                Some(offset) => offset,
-                                None => {
-                                    let expected = guard.next_rtp_timestamp.unwrap_or(src_ts);
-                                    let offset = expected.wrapping_sub(src_ts);
-                                    guard.active_rtp_offset = Some(offset);
-                                    offset
-                                }
-                            };
-
-                            let mapped_seq = src_seq.wrapping_add(seq_offset);
-                            let mapped_ts = src_ts.wrapping_add(ts_offset);
-                            frame.sequence_number = Some(mapped_seq);
-                            frame.rtp_timestamp = mapped_ts;
-
-                            guard.next_sequence_number = Some(mapped_seq.wrapping_add(1));
-                            guard.next_rtp_timestamp =
-                                Some(mapped_ts.wrapping_add(frame_ticks_20ms(frame.clock_rate)));
+                            output_state.lock().await.map_to_output_timeline(frame);
                         }
 
                         let Some(sender) = sender.as_ref() else {
@@ -1124,6 +1325,8 @@ impl BridgePeer {
         let bridge_id = self.id.clone();
         let w2r_stats = Arc::clone(&self.webrtc_to_rtp_stats);
         let r2w_stats = Arc::clone(&self.rtp_to_webrtc_stats);
+        let dedup_window = self.rtp_dedup_window;
+        let max_payload = self.max_rtp_payload;
         let recorder = self.recorder.clone();
         let dtmf_sink = Arc::clone(&self.dtmf_sink);
         let webrtc_to_rtp_transcoder = Arc::clone(&self.webrtc_to_rtp_transcoder);
@@ -1228,6 +1431,8 @@ impl BridgePeer {
                                         cancel_token.clone(),
                                         ForwardPath::new(LegTransport::WebRtc, LegTransport::Rtp),
                                         Arc::clone(&w2r_stats),
+                                        dedup_window,
+                                        max_payload,
                                         if !is_video { recorder.clone() } else { None },
                                         if !is_video { Some(RecLeg::A) } else { None },
                                         Arc::clone(&dtmf_sink),
@@ -1339,6 +1544,8 @@ impl BridgePeer {
                                         cancel_token.clone(),
                                         ForwardPath::new(LegTransport::Rtp, LegTransport::WebRtc),
                                         Arc::clone(&r2w_stats),
+                                        dedup_window,
+                                        max_payload,
                                         if !is_video { recorder.clone() } else { None },
                                         if !is_video { Some(RecLeg::B) } else { None },
                                         Arc::clone(&dtmf_sink),
@@ -1380,6 +1587,8 @@ impl BridgePeer {
         cancel_token: CancellationToken,
         path: ForwardPath,
         leg_stats: Arc<LegStats>,
+        dedup_window: u16,
+        max_payload: usize,
         recorder: Option<Arc<parking_lot::RwLock<Option<Recorder>>>>,
         recorder_leg: Option<RecLeg>,
         dtmf_sink: Arc<parking_lot::RwLock<Option<BridgeDtmfSink>>>,
@@ -1406,6 +1615,7 @@ impl BridgePeer {
         let mut packet_count: u64 = 0;
         // Last seen RTP sequence number for loss estimation
         let mut last_seq: Option<u16> = None;
+        let mut deduplicator = RtpDeduplicator::new(dedup_window);
         // Per-second stats for video diagnostics
         let mut stats_packets: u64 = 0;
         let mut stats_bytes: u64 = 0;
@@ -1435,6 +1645,30 @@ impl BridgePeer {
                 sample_result = track.recv() => {
                     match sample_result {
                         Ok(sample) => {
+                            if let MediaSample::Audio(frame) = &sample
+                                && !audio_payload_plausible(frame.payload_type, frame.data.len(), max_payload)
+                            {
+                                leg_stats.rejected.fetch_add(1, Ordering::Relaxed);
+                                crate::metrics::media::rtp_payload_rejected(&path.to_string());
+                                debug!(
+                                    bridge_id = %bridge_id,
+                                    direction = %path,
+                                    payload_type = ?frame.payload_type,
+                                    len = frame.data.len(),
+                                    "Dropping audio packet with implausible payload size"
+                                );
+                                continue;
+                            }
+                            if let MediaSample::Audio(frame) = &sample
+                                && let Some(seq) = frame.sequence_number
+                            {
+                                let ssrc = frame.raw_packet.as_ref().map(|p| p.header.ssrc);
+                                if deduplicator.is_duplicate(seq, ssrc, frame.rtp_timestamp) {
+                                    leg_stats.duplicates.fetch_add(1, Ordering::Relaxed);
+                                    trace!(bridge_id = %bridge_id, direction = %path, seq, "Dropping duplicate RTP packet");
+                                    continue;
+                                }
+                            }
                             packet_count += 1;
                             if !is_video {
                                 Self::observe_dtmf_sample(
@@ -1702,6 +1936,8 @@ impl BridgePeer {
         cancel_token: CancellationToken,
         path: ForwardPath,
         leg_stats: Arc<LegStats>,
+        dedup_window: u16,
+        max_payload: usize,
         recorder: Option<Arc<parking_lot::RwLock<Option<Recorder>>>>,
         recorder_leg: Option<RecLeg>,
         dtmf_sink: Arc<parking_lot::RwLock<Option<BridgeDtmfSink>>>,
@@ -1717,6 +1953,8 @@ impl BridgePeer {
                 cancel_token,
                 path,
                 leg_stats,
+                dedup_window,
+                max_payload,
                 recorder,
                 recorder_leg,
                 dtmf_sink,
@@ -1785,6 +2023,8 @@ pub struct BridgePeerBuilder {
     rtp_sdp_compatibility: rustrtc::config::SdpCompatibilityMode,
     ice_servers: Vec<IceServer>,
     recorder: Option<Arc<parking_lot::RwLock<Option<Recorder>>>>,
+    rtp_dedup_window: u16,
+    max_rtp_payload: usize,
 }
 
 impl BridgePeerBuilder {
@@ -1806,6 +2046,8 @@ impl BridgePeerBuilder {
             rtp_sdp_compatibility: rustrtc::config::SdpCompatibilityMode::LegacySip,
             ice_servers: Vec::new(),
             recorder: None,
+            rtp_dedup_window: DEFAULT_RTP_DEDUP_WINDOW,
+            max_rtp_payload: DEFAULT_MAX_RTP_PAYLOAD,
         }
     }
 
@@ -1916,6 +2158,21 @@ impl BridgePeerBuilder {
         self
     }
 
+    /// Number of recent RTP sequence numbers (up to 64) checked to drop
+    /// duplicate audio packets before forwarding. `0` forwards everything.
+    pub fn with_rtp_dedup_window(mut self, window: u16) -> Self {
+        self.rtp_dedup_window = window;
+        self
+    }
+
+    /// Largest audio RTP payload, in bytes, forwarded by the bridge. Larger
+    /// packets, or G.711/G.722/G.729 packets carrying more than 120ms of
+    /// audio, are dropped. `0` forwards everything.
+    pub fn with_max_rtp_payload(mut self, max_payload: usize) -> Self {
+        self.max_rtp_payload = max_payload;
+        self
+    }
+
     fn default_video_capabilities() -> Vec<rustrtc::config::VideoCapability> {
         vec![
             rustrtc::config::VideoCapability {
@@ -2003,6 +2260,8 @@ impl BridgePeerBuilder {
         bridge.webrtc_sender_codec = self.webrtc_sender_codec;
         bridge.rtp_sender_codec = self.rtp_sender_codec;
         bridge.recorder = self.recorder;
+        bridge.rtp_dedup_window = self.rtp_dedup_window;
+        bridge.max_rtp_payload = self.max_rtp_payload;
 
         // Store video codec params for setup_bridge to create video senders
         bridge.webrtc_video_codec = self
@@ -2236,6 +2495,139 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_bridge_inject_flush_without_injection_keeps_file_playback() {
+        let temp_dir = std::env::temp_dir();
+        let test_file = temp_dir.join("test_bridge_inject_flush_file.wav");
+        create_test_wav_file(test_file.to_str().unwrap(), 800).unwrap();
+
+        let bridge = BridgePeerBuilder::new("test-bridge-inject-flush".to_string())
+            .with_rtp_port_range(25500, 25600)
+            .build();
+        bridge.setup_bridge().await.unwrap();
+        let codec_info = crate::media::negotiate::CodecInfo {
+            payload_type: 0,
+            codec: CodecType::PCMU,
+            clock_rate: 8000,
+            channels: 1,
+        };
+        let track = FileTrack::new("bridge-inject-flush".to_string())
+            .with_path(test_file.to_string_lossy().to_string())
+            .with_loop(false)
+            .with_codec_info(codec_info.clone());
+        bridge
+            .replace_output_with_file(BridgeEndpoint::Rtp, &track)
+            .await
+            .unwrap();
+        drop(track);
+
+        // What media.inject_stop sends when nothing was injected
+        let sent = bridge
+            .inject_pcm(BridgeEndpoint::Rtp, codec_info, &[], 8000, true)
+            .await
+            .unwrap();
+        assert_eq!(sent, 0);
+        assert_eq!(
+            bridge.rtp_output_mode.load(Ordering::Acquire),
+            BRIDGE_OUTPUT_FILE
+        );
+        assert!(bridge.rtp_output_state.lock().await.file_source.is_some());
+
+        let rtp_track = bridge
+            .get_rtp_track()
+            .await
+            .expect("bridge RTP output track should exist");
+        let mut frame_count = 0;
+        let deadline = tokio::time::Instant::now() + tokio::time::Duration::from_millis(400);
+        while tokio::time::Instant::now() < deadline && frame_count < 3 {
+            match tokio::time::timeout(tokio::time::Duration::from_millis(100), rtp_track.recv())
+                .await
+            {
+                Ok(Ok(MediaSample::Audio(_))) => frame_count += 1,
+                Ok(Ok(_)) => {}
+                Ok(Err(_)) => break,
+                Err(_) => {}
+            }
+        }
+
+        bridge.stop().await;
+        let _ = std::fs::remove_file(&test_file);
+
+        assert!(frame_count >= 3, "file playback should continue");
+    }
+
+    #[tokio::test]
+    async fn test_bridge_inject_pcm_continues_output_timeline() {
+        let bridge = BridgePeerBuilder::new("test-bridge-inject".to_string())
+            .with_rtp_port_range(25300, 25400)
+            .build();
+        bridge.setup_bridge().await.unwrap();
+        let codec_info = crate::media::negotiate::CodecInfo {
+            payload_type: 0,
+            codec: CodecType::PCMU,
+            clock_rate: 8000,
+            channels: 1,
+        };
+        {
+            let mut state = bridge.rtp_output_state.lock().await;
+            state.next_sequence_number = Some(1000);
+            state.next_rtp_timestamp = Some(48000);
+        }
+
+        // 50 ms at 16 kHz -> two frames now, the 10 ms remainder on flush
+        let sent = bridge
+            .inject_pcm(
+                BridgeEndpoint::Rtp,
+                codec_info.clone(),
+                &[0; 800],
+                16000,
+                false,
+            )
+            .await
+            .unwrap();
+        assert_eq!(sent, 2);
+        assert_eq!(
+            bridge.rtp_output_mode.load(Ordering::Acquire),
+            BRIDGE_OUTPUT_INJECT
+        );
+        let sent = bridge
+            .inject_pcm(BridgeEndpoint::Rtp, codec_info, &[], 16000, true)
+            .await
+            .unwrap();
+        assert_eq!(sent, 1);
+        assert_eq!(
+            bridge.rtp_output_mode.load(Ordering::Acquire),
+            BRIDGE_OUTPUT_PEER
+        );
+
+        let rtp_track = bridge
+            .get_rtp_track()
+            .await
+            .expect("bridge RTP output track should exist");
+        let mut timeline = Vec::new();
+        while timeline.len() < 3 {
+            match tokio::time::timeout(tokio::time::Duration::from_millis(200), rtp_track.recv())
+                .await
+            {
+                Ok(Ok(MediaSample::Audio(frame))) => {
+                    timeline.push((frame.sequence_number, frame.rtp_timestamp))
+                }
+                Ok(Ok(_)) => {}
+                _ => break,
+            }
+        }
+        bridge.stop().await;
+
+        assert_eq!(
+            timeline,
+            vec![
+                (Some(1000), 48000),
+                (Some(1001), 48160),
+                (Some(1002), 48320)
+            ]
+        );
+    }
+
     /// Test that BridgePeer correctly handles SDP format differences
     #[tokio::test]
     async fn test_bridge_sdp_format_differences() {
@@ -3187,6 +3579,8 @@ mod tests {
                     c,
                     ForwardPath::new(LegTransport::Rtp, LegTransport::WebRtc),
                     st,
+                    DEFAULT_RTP_DEDUP_WINDOW,
+                    DEFAULT_MAX_RTP_PAYLOAD,
                     None, // no recorder
                     None, // no recorder leg
                     ds,
@@ -3450,6 +3844,8 @@ mod tests {
                     c,
                     ForwardPath::new(LegTransport::Rtp, LegTransport::WebRtc),
                     st,
+                    DEFAULT_RTP_DEDUP_WINDOW,
+                    DEFAULT_MAX_RTP_PAYLOAD,
                     None,
                     None,
                     ds,
@@ -3479,4 +3875,184 @@ mod tests {
             other => panic!("Expected passthrough PCMU Audio frame, got {:?}", other),
         }
     }
+
+    struct QueuedAudioTrack {
+        samples: tokio::sync::Mutex<std::collections::VecDeque<MediaSample>>,
+    }
+
+    #[async_trait::async_trait]
+    impl MediaStreamTrack for QueuedAudioTrack {
+        fn id(&self) -> &str {
+            "queued-audio"
+        }
+        fn kind(&self) -> MediaKind {
+            MediaKind::Audio
+        }
+        fn state(&self) -> TrackState {
+            TrackState::Live
+        }
+        async fn recv(&self) -> MediaResult<MediaSample> {
+            if let Some(sample) = self.samples.lock().await.pop_front() {
+                return Ok(sample);
+            }
+            loop {
+                tokio::time::sleep(std::time::Duration::from_secs(999)).await;
+            }
+        }
+        async fn request_key_frame(&self) -> MediaResult<()> {
+            Ok(())
+        }
+    }
+
+    /// Retransmitted packets (repeated sequence numbers) are forwarded once.
+    #[tokio::test]
+    async fn test_bridge_forwarding_drops_duplicate_sequence_numbers() {
+        use rustrtc::media::track::sample_track;
+
+        let sequence = [1u16, 2, 2, 3, 1, 3, 4];
+        let samples = sequence
+            .iter()
+            .map(|&seq| {
+                MediaSample::Audio(AudioFrame {
+                    rtp_timestamp: seq as u32 * 160,
+                    clock_rate: 8000,
+                    data: vec![seq as u8; 160].into(),
+                    sequence_number: Some(seq),
+                    payload_type: Some(0),
+                    ..Default::default()
+                })
+            })
+            .collect();
+        let track: Arc<dyn MediaStreamTrack> = Arc::new(QueuedAudioTrack {
+            samples: tokio::sync::Mutex::new(samples),
+        });
+
+        let (output_tx, output_track, _) = sample_track(MediaKind::Audio, 16);
+        let sender_arc = Arc::new(AsyncMutex::new(Some(output_tx)));
+        let cancel = CancellationToken::new();
+        let stats = LegStats::new();
+
+        let task_handle = tokio::spawn(BridgePeer::run_forward_loop(
+            "test-dedup".to_string(),
+            track,
+            Arc::downgrade(&sender_arc),
+            Arc::new(AtomicU8::new(BRIDGE_OUTPUT_PEER)),
+            cancel.clone(),
+            ForwardPath::new(LegTransport::Rtp, LegTransport::WebRtc),
+            stats.clone(),
+            DEFAULT_RTP_DEDUP_WINDOW,
+            DEFAULT_MAX_RTP_PAYLOAD,
+            None,
+            None,
+            Arc::new(parking_lot::RwLock::new(None)),
+            None,
+            None,
+        ));
+
+        let mut forwarded = Vec::new();
+        while let Ok(Ok(MediaSample::Audio(frame))) =
+            tokio::time::timeout(std::time::Duration::from_millis(300), output_track.recv()).await
+        {
+            forwarded.push(frame.data[0]);
+        }
+        cancel.cancel();
+        let _ = task_handle.await;
+
+        assert_eq!(forwarded, vec![1, 2, 3, 4]);
+        assert_eq!(stats.duplicates.load(Ordering::Relaxed), 3);
+    }
+
+    /// Oversized audio payloads are dropped and counted, never forwarded.
+    #[tokio::test]
+    async fn test_bridge_forwarding_rejects_oversized_payloads() {
+        use rustrtc::media::track::sample_track;
+
+        // (sequence, payload type, payload bytes)
+        let packets = [
+            (1u16, 0u8, 160usize),
+            (2, 0, 4000),  // over the MTU-sized cap
+            (3, 0, 1200),  // 150ms of PCMU
+            (4, 96, 1200), // dynamic payload type: cap only
+            (5, 0, 160),
+        ];
+        let samples = packets
+            .iter()
+            .map(|&(seq, pt, len)| {
+                MediaSample::Audio(AudioFrame {
+                    rtp_timestamp: seq as u32 * 160,
+                    clock_rate: 8000,
+                    data: vec![seq as u8; len].into(),
+                    sequence_number: Some(seq),
+                    payload_type: Some(pt),
+                    ..Default::default()
+                })
+            })
+            .collect();
+        let track: Arc<dyn MediaStreamTrack> = Arc::new(QueuedAudioTrack {
+            samples: tokio::sync::Mutex::new(samples),
+        });
+
+        let (output_tx, output_track, _) = sample_track(MediaKind::Audio, 16);
+        let sender_arc = Arc::new(AsyncMutex::new(Some(output_tx)));
+        let cancel = CancellationToken::new();
+        let stats = LegStats::new();
+
+        let task_handle = tokio::spawn(BridgePeer::run_forward_loop(
+            "test-oversized".to_string(),
+            track,
+            Arc::downgrade(&sender_arc),
+            Arc::new(AtomicU8::new(BRIDGE_OUTPUT_PEER)),
+            cancel.clone(),
+            ForwardPath::new(LegTransport::Rtp, LegTransport::WebRtc),
+            stats.clone(),
+            DEFAULT_RTP_DEDUP_WINDOW,
+            DEFAULT_MAX_RTP_PAYLOAD,
+            None,
+            None,
+            Arc::new(parking_lot::RwLock::new(None)),
+            None,
+            None,
+        ));
+
+        let mut forwarded = Vec::new();
+        while let Ok(Ok(MediaSample::Audio(frame))) =
+            tokio::time::timeout(std::time::Duration::from_millis(300), output_track.recv()).await
+        {
+            forwarded.push(frame.data[0]);
+        }
+        cancel.cancel();
+        let _ = task_handle.await;
+
+        assert_eq!(forwarded, vec![1, 4, 5]);
+        assert_eq!(stats.rejected.load(Ordering::Relaxed), 2);
+        assert_eq!(stats.packets.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn test_rtp_deduplicator_restarts_on_new_stream() {
+        let mut dedup = RtpDeduplicator::new(DEFAULT_RTP_DEDUP_WINDOW);
+        assert!(!dedup.is_duplicate(65535, Some(1), 0));
+        assert!(
+            !dedup.is_duplicate(0, Some(1), 160),
+            "wraparound is not a duplicate"
+        );
+        assert!(dedup.is_duplicate(65535, Some(1), 0));
+        // Same sequence numbers from a new SSRC belong to a different stream
+        assert!(!dedup.is_duplicate(0, Some(2), 160));
+        // A far jump starts a fresh window instead of matching stale state
+        assert!(!dedup.is_duplicate(30000, Some(2), 320));
+        assert!(!dedup.is_duplicate(0, Some(2), 160));
+
+        // Without an SSRC, a restarted stream is recognized by its timestamp
+        let mut no_ssrc = RtpDeduplicator::new(DEFAULT_RTP_DEDUP_WINDOW);
+        assert!(!no_ssrc.is_duplicate(100, None, 8000));
+        assert!(!no_ssrc.is_duplicate(101, None, 8160));
+        assert!(no_ssrc.is_duplicate(100, None, 8000));
+        assert!(!no_ssrc.is_duplicate(90, None, 3_000_000));
+        assert!(!no_ssrc.is_duplicate(91, None, 3_000_160));
+
+        let mut disabled = RtpDeduplicator::new(0);
+        assert!(!disabled.is_duplicate(7, None, 0));
+        assert!(!disabled.is_duplicate(7, None, 0));
+    }
 }
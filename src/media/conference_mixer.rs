@@ -4,7 +4,7 @@
 //! It connects MediaPeers to the mixer and routes mixed audio back to participants.
 
 use crate::call::domain::LegId;
-use crate::media::mixer::AudioMixer;
+use crate::media::mixer::{AudioMixer, LimiterMode};
 use anyhow::{Result, anyhow};
 use audio_codec::CodecType;
 use std::collections::HashMap;
@@ -69,6 +69,8 @@ pub struct ConferenceAudioMixer {
     sample_rate: u32,
     /// Frame size in samples (e.g., 160 for 20ms at 8kHz)
     frame_size: usize,
+    /// How overflowing mixed samples are limited
+    limiter: LimiterMode,
     /// Cancellation token for stopping
     cancel_token: CancellationToken,
     /// Mixing task handle
@@ -81,6 +83,7 @@ impl std::fmt::Debug for ConferenceAudioMixer {
             .field("conf_id", &self.conf_id)
             .field("sample_rate", &self.sample_rate)
             .field("frame_size", &self.frame_size)
+            .field("limiter", &self.limiter)
             .finish_non_exhaustive()
     }
 }
@@ -96,11 +99,22 @@ impl ConferenceAudioMixer {
             participant_count: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
             sample_rate,
             frame_size,
+            limiter: LimiterMode::default(),
             cancel_token: CancellationToken::new(),
             mixing_task: Arc::new(std::sync::Mutex::new(None)),
         }
     }
 
+    /// Select the limiter applied to mixed audio before it is sent out
+    pub fn with_limiter(mut self, limiter: LimiterMode) -> Self {
+        self.limiter = limiter;
+        self
+    }
+
+    pub fn limiter(&self) -> LimiterMode {
+        self.limiter
+    }
+
     /// Add a participant to the conference
     /// Returns channels for sending/receiving audio
     pub async fn add_participant(
@@ -216,10 +230,19 @@ impl ConferenceAudioMixer {
         let participants = self.participants.clone();
         let frame_size = self.frame_size;
         let sample_rate = self.sample_rate;
+        let limiter = self.limiter;
         let conf_id = self.conf_id.clone();
 
         let task = tokio::spawn(async move {
-            Self::mixing_loop(conf_id, participants, cancel_token, frame_size, sample_rate).await;
+            Self::mixing_loop(
+                conf_id,
+                participants,
+                cancel_token,
+                frame_size,
+                sample_rate,
+                limiter,
+            )
+            .await;
         });
 
         let mut mixing_task = self.mixing_task.lock().unwrap();
@@ -253,6 +276,7 @@ impl ConferenceAudioMixer {
         cancel_token: CancellationToken,
         frame_size: usize,
         sample_rate: u32,
+        limiter: LimiterMode,
     ) {
         let interval_ms = (frame_size as f64 / sample_rate as f64 * 1000.0) as u64;
         let interval = tokio::time::Duration::from_millis(interval_ms.max(1));
@@ -266,7 +290,7 @@ impl ConferenceAudioMixer {
         );
 
         // Audio mixer for combining frames
-        let audio_mixer = AudioMixer::new(sample_rate, 1);
+        let audio_mixer = AudioMixer::new(sample_rate, 1).with_limiter(limiter);
 
         loop {
             tokio::select! {
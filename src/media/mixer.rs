@@ -1,14 +1,50 @@
 use crate::proxy::proxy_call::media_peer::MediaPeer;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, info};
 
+/// How mixed samples that exceed the i16 range are brought back into range.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LimiterMode {
+    /// Clamp at full scale
+    #[default]
+    HardClip,
+    /// Pass quiet samples unchanged and compress peaks above the knee
+    /// smoothly toward full scale
+    SoftKnee,
+}
+
+/// Fraction of full scale where the soft-knee limiter starts compressing
+const SOFT_KNEE_THRESHOLD: f32 = 0.8 * i16::MAX as f32;
+
+impl LimiterMode {
+    /// Bring an accumulated mix sample back into i16 range
+    pub fn limit(self, sample: f32) -> i16 {
+        match self {
+            LimiterMode::HardClip => sample.clamp(i16::MIN as f32, i16::MAX as f32) as i16,
+            LimiterMode::SoftKnee => {
+                let magnitude = sample.abs();
+                if magnitude <= SOFT_KNEE_THRESHOLD {
+                    return sample as i16;
+                }
+                let headroom = i16::MAX as f32 - SOFT_KNEE_THRESHOLD;
+                let compressed = SOFT_KNEE_THRESHOLD
+                    + headroom * ((magnitude - SOFT_KNEE_THRESHOLD) / headroom).tanh();
+                (compressed.copysign(sample)) as i16
+            }
+        }
+    }
+}
+
 /// Simple PCM frame mixer
 pub struct AudioMixer {
     _sample_rate: u32,
     _channels: u16,
+    limiter: LimiterMode,
 }
 
 impl AudioMixer {
@@ -16,9 +52,15 @@ impl AudioMixer {
         Self {
             _sample_rate: sample_rate,
             _channels: channels,
+            limiter: LimiterMode::default(),
         }
     }
 
+    pub fn with_limiter(mut self, limiter: LimiterMode) -> Self {
+        self.limiter = limiter;
+        self
+    }
+
     /// Mix multiple frames with individual gains
     /// Each frame should be the same length
     pub fn mix_frames(&self, frames: Vec<Vec<i16>>, gains: &[f32]) -> Vec<i16> {
@@ -27,20 +69,19 @@ impl AudioMixer {
         }
 
         let frame_len = frames[0].len();
-        let mut output = vec![0i16; frame_len];
+        let mut sums = vec![0f32; frame_len];
 
         for (frame, &gain) in frames.iter().zip(gains) {
             if frame.len() != frame_len {
                 continue;
             }
             for (i, sample) in frame.iter().enumerate() {
-                // Apply gain and mix
-                let mixed = (output[i] as f32 + (*sample as f32) * gain) as i16;
-                output[i] = mixed.clamp(i16::MIN, i16::MAX);
+                // Accumulate at full precision; limiting happens once at the end
+                sums[i] += *sample as f32 * gain;
             }
         }
 
-        output
+        sums.into_iter().map(|s| self.limiter.limit(s)).collect()
     }
 }
 
@@ -654,6 +695,47 @@ mod tests {
         assert!(result.iter().all(|&s| s == i16::MAX));
     }
 
+    #[test]
+    fn test_soft_knee_limiter_stays_in_range() {
+        let hard = AudioMixer::new(8000, 1);
+        let soft = AudioMixer::new(8000, 1).with_limiter(LimiterMode::SoftKnee);
+
+        // Two loud in-phase tones whose sum overflows i16
+        let tone: Vec<i16> = (0..160)
+            .map(|i| ((i as f32 / 160.0 * std::f32::consts::TAU).sin() * 24000.0) as i16)
+            .collect();
+        let frames = vec![tone.clone(), tone.clone()];
+        let gains = [1.0, 1.0];
+
+        let hard_out = hard.mix_frames(frames.clone(), &gains);
+        let soft_out = soft.mix_frames(frames, &gains);
+
+        for (i, (&h, &s)) in hard_out.iter().zip(&soft_out).enumerate() {
+            let expected_sign = tone[i].signum();
+            // No wraparound: output keeps the sign of the input
+            assert_eq!(h.signum(), expected_sign);
+            assert_eq!(s.signum(), expected_sign);
+            assert!(s.unsigned_abs() <= i16::MAX as u16);
+        }
+
+        // Hard clipping flattens the peaks; with two parties the soft knee
+        // keeps them below full scale (louder mixes still saturate)
+        let clipped = hard_out
+            .iter()
+            .filter(|s| s.unsigned_abs() >= i16::MAX as u16)
+            .count();
+        let soft_clipped = soft_out
+            .iter()
+            .filter(|s| s.unsigned_abs() >= i16::MAX as u16)
+            .count();
+        assert!(clipped > 0);
+        assert_eq!(soft_clipped, 0);
+
+        // Quiet passages below the knee are untouched
+        assert_eq!(LimiterMode::SoftKnee.limit(1000.0), 1000);
+        assert_eq!(LimiterMode::SoftKnee.limit(-1000.0), -1000);
+    }
+
     #[test]
     fn test_apply_supervisor_mode_listen() {
         let mixer = MediaMixer::new("test-apply-listen".to_string(), 8000);
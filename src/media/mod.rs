@@ -31,6 +31,7 @@ pub mod mixer_input;
 pub mod mixer_output;
 pub mod mixer_registry;
 pub mod negotiate;
+pub mod pcm_inject;
 pub mod sdp_bridge;
 pub mod telephone_event;
 pub mod transcoder;
@@ -103,6 +104,11 @@ pub trait Track: Send + Sync {
         None
     }
 
+    /// Negotiated media state, once an offer/answer has completed.
+    fn media_info(&self) -> Option<negotiate::MediaInfo> {
+        None
+    }
+
     /// Allow downcasting to concrete types for dynamic audio source switching
     fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
         unimplemented!("as_any_mut not implemented for this Track type")
@@ -266,6 +272,8 @@ pub struct RtcTrack {
     pub recorder_option: Option<RecorderOption>,
     rtp_map: Vec<negotiate::CodecInfo>,
     muted: std::sync::atomic::AtomicBool,
+    /// Whether our description is the answer (set when handling a remote offer)
+    local_is_answer: std::sync::atomic::AtomicBool,
     /// Sender for injecting audio samples into the PeerConnection
     sender: Option<SampleStreamSource>,
 }
@@ -295,6 +303,7 @@ impl RtcTrack {
             recorder_option: None,
             rtp_map,
             muted: std::sync::atomic::AtomicBool::new(false),
+            local_is_answer: std::sync::atomic::AtomicBool::new(false),
             sender: Some(tx),
         }
     }
@@ -336,6 +345,7 @@ impl RtcTrack {
             recorder_option: None,
             rtp_map,
             muted: std::sync::atomic::AtomicBool::new(false),
+            local_is_answer: std::sync::atomic::AtomicBool::new(false),
             sender: Some(tx),
         }
     }
@@ -414,6 +424,8 @@ impl Track for RtcTrack {
             .await?;
         let answer = self.pc.create_answer().await?;
         let sdp = self.set_local(&self.pc, answer).await?;
+        self.local_is_answer
+            .store(true, std::sync::atomic::Ordering::Relaxed);
         Ok(sdp)
     }
 
@@ -453,6 +465,19 @@ impl Track for RtcTrack {
         self.rtp_map.first().cloned()
     }
 
+    fn media_info(&self) -> Option<negotiate::MediaInfo> {
+        let local = self.pc.local_description()?.to_sdp_string();
+        let remote = self.pc.remote_description()?.to_sdp_string();
+        let local_is_answer = self
+            .local_is_answer
+            .load(std::sync::atomic::Ordering::Relaxed);
+        Some(negotiate::MediaNegotiator::media_info(
+            &local,
+            &remote,
+            local_is_answer,
+        ))
+    }
+
     async fn set_muted(&self, muted: bool) -> bool {
         self.muted
             .store(muted, std::sync::atomic::Ordering::Relaxed);
@@ -95,6 +95,20 @@ pub struct NegotiatedLegProfile {
     pub dtmf: Option<NegotiatedCodec>,
 }
 
+/// Snapshot of a leg's negotiated media, for diagnostics.
+#[derive(Debug, Clone, Default)]
+pub struct MediaInfo {
+    pub codec: Option<NegotiatedCodec>,
+    pub dtmf: Option<NegotiatedCodec>,
+    pub ptime: Option<u32>,
+    /// Direction of the local audio section.
+    pub direction: Option<rustrtc::Direction>,
+    pub local_ssrc: Option<u32>,
+    pub remote_ssrc: Option<u32>,
+    pub local_rtp_port: Option<u16>,
+    pub remote_rtp_port: Option<u16>,
+}
+
 /// Media negotiator for SDP parsing and codec selection
 pub struct MediaNegotiator;
 
@@ -804,6 +818,46 @@ impl MediaNegotiator {
         }
     }
 
+    /// Build a [`MediaInfo`] from both sides of a completed offer/answer.
+    /// `local_is_answer` tells which description carries the selected codec.
+    pub fn media_info(local_sdp: &str, remote_sdp: &str, local_is_answer: bool) -> MediaInfo {
+        let answer = if local_is_answer {
+            local_sdp
+        } else {
+            remote_sdp
+        };
+        let profile = Self::extract_leg_profile(answer);
+        let local_section = Self::parse_audio_section(local_sdp);
+        let ptime = [answer, local_sdp, remote_sdp]
+            .into_iter()
+            .filter_map(Self::parse_audio_section)
+            .find_map(|section| {
+                section
+                    .attributes
+                    .iter()
+                    .find(|a| a.key == "ptime")
+                    .and_then(|a| a.value.as_ref()?.trim().parse::<u32>().ok())
+            });
+
+        MediaInfo {
+            codec: profile.audio,
+            dtmf: profile.dtmf,
+            ptime,
+            direction: local_section.map(|section| section.direction),
+            local_ssrc: Self::extract_ssrc(local_sdp),
+            remote_ssrc: Self::extract_ssrc(remote_sdp),
+            local_rtp_port: Self::audio_port(local_sdp),
+            remote_rtp_port: Self::audio_port(remote_sdp),
+        }
+    }
+
+    fn audio_port(sdp: &str) -> Option<u16> {
+        sdp.lines()
+            .find_map(|line| line.trim().strip_prefix("m=audio "))
+            .and_then(|rest| rest.split_whitespace().next())
+            .and_then(|port| port.parse::<u16>().ok())
+    }
+
     pub fn extract_ssrc(sdp: &str) -> Option<u32> {
         // Try parsing as Answer first, then Offer if it fails (though usually it's Answer)
         let session = SessionDescription::parse(SdpType::Answer, sdp)
@@ -2263,4 +2317,43 @@ a=rtpmap:8 PCMA/8000\r\n";
             m_line
         );
     }
+
+    #[test]
+    fn test_media_info_reflects_answer() {
+        let offer = "v=0\r\n\
+            o=- 1234 1234 IN IP4 192.0.2.10\r\n\
+            s=-\r\n\
+            c=IN IP4 192.0.2.10\r\n\
+            t=0 0\r\n\
+            m=audio 40000 RTP/AVP 0 8 101\r\n\
+            a=rtpmap:0 PCMU/8000\r\n\
+            a=rtpmap:8 PCMA/8000\r\n\
+            a=rtpmap:101 telephone-event/8000\r\n\
+            a=ssrc:1111 cname:remote\r\n\
+            a=sendrecv\r\n";
+        let answer = "v=0\r\n\
+            o=- 5678 5678 IN IP4 192.0.2.20\r\n\
+            s=-\r\n\
+            c=IN IP4 192.0.2.20\r\n\
+            t=0 0\r\n\
+            m=audio 20000 RTP/AVP 8 101\r\n\
+            a=rtpmap:8 PCMA/8000\r\n\
+            a=rtpmap:101 telephone-event/8000\r\n\
+            a=ptime:20\r\n\
+            a=ssrc:2222 cname:local\r\n\
+            a=recvonly\r\n";
+
+        let info = MediaNegotiator::media_info(answer, offer, true);
+
+        let codec = info.codec.expect("negotiated codec");
+        assert_eq!(codec.codec, CodecType::PCMA);
+        assert_eq!(codec.payload_type, 8);
+        assert_eq!(info.dtmf.map(|d| d.payload_type), Some(101));
+        assert_eq!(info.ptime, Some(20));
+        assert_eq!(info.direction, Some(rustrtc::Direction::RecvOnly));
+        assert_eq!(info.local_ssrc, Some(2222));
+        assert_eq!(info.remote_ssrc, Some(1111));
+        assert_eq!(info.local_rtp_port, Some(20000));
+        assert_eq!(info.remote_rtp_port, Some(40000));
+    }
 }
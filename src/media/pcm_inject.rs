@@ -0,0 +1,146 @@
+use super::{audio_frame_timing, negotiate::CodecInfo};
+use anyhow::{Result, anyhow};
+use audio_codec::{Encoder, Resampler, create_encoder};
+use rustrtc::media::{AudioFrame, MediaSample};
+
+/// Turns application-supplied PCM into 20 ms RTP frames for a negotiated
+/// codec, resampling as needed. Keeps the RTP clock running across calls so
+/// successive injections form one continuous stream; the media bridge maps
+/// that stream onto the endpoint's output timeline.
+pub struct PcmInjector {
+    codec_info: CodecInfo,
+    encoder: Box<dyn Encoder>,
+    resampler: Option<(u32, Resampler)>,
+    samples_per_frame: usize,
+    rtp_ticks_per_frame: u32,
+    rtp_timestamp: u32,
+    sequence_number: u16,
+    pending: Vec<i16>,
+}
+
+impl PcmInjector {
+    pub fn new(codec_info: CodecInfo) -> Self {
+        let timing = audio_frame_timing(codec_info.codec, codec_info.clock_rate);
+        Self {
+            encoder: create_encoder(codec_info.codec),
+            codec_info,
+            resampler: None,
+            samples_per_frame: timing.pcm_samples_per_frame,
+            rtp_ticks_per_frame: timing.rtp_ticks_per_frame,
+            rtp_timestamp: rand::random(),
+            sequence_number: rand::random(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Queue `pcm` (mono, at `sample_rate`) and return every complete frame.
+    /// A partial trailing frame is kept until the next push or [`Self::flush`].
+    pub fn push(&mut self, pcm: &[i16], sample_rate: u32) -> Result<Vec<MediaSample>> {
+        if sample_rate == 0 {
+            return Err(anyhow!("injected PCM has no sample rate"));
+        }
+        let target_rate = self.encoder.sample_rate();
+        if sample_rate == target_rate {
+            self.pending.extend_from_slice(pcm);
+        } else {
+            if self.resampler.as_ref().map(|(rate, _)| *rate) != Some(sample_rate) {
+                self.resampler = Some((
+                    sample_rate,
+                    Resampler::new(sample_rate as usize, target_rate as usize),
+                ));
+            }
+            if let Some((_, resampler)) = self.resampler.as_mut() {
+                self.pending.extend(resampler.resample(pcm));
+            }
+        }
+
+        let mut frames = Vec::new();
+        while self.pending.len() >= self.samples_per_frame {
+            let chunk: Vec<i16> = self.pending.drain(..self.samples_per_frame).collect();
+            frames.push(self.encode_frame(&chunk));
+        }
+        Ok(frames)
+    }
+
+    /// Pad any buffered remainder with silence and emit it as a final frame.
+    pub fn flush(&mut self) -> Option<MediaSample> {
+        if self.pending.is_empty() {
+            return None;
+        }
+        let mut chunk = std::mem::take(&mut self.pending);
+        chunk.resize(self.samples_per_frame, 0);
+        Some(self.encode_frame(&chunk))
+    }
+
+    fn encode_frame(&mut self, pcm: &[i16]) -> MediaSample {
+        let frame = AudioFrame {
+            rtp_timestamp: self.rtp_timestamp,
+            clock_rate: self.codec_info.clock_rate,
+            data: self.encoder.encode(pcm).into(),
+            sequence_number: Some(self.sequence_number),
+            payload_type: Some(self.codec_info.payload_type),
+            ..Default::default()
+        };
+        self.rtp_timestamp = self.rtp_timestamp.wrapping_add(self.rtp_ticks_per_frame);
+        self.sequence_number = self.sequence_number.wrapping_add(1);
+        MediaSample::Audio(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::media::negotiate::MediaNegotiator;
+    use audio_codec::{CodecType, create_decoder};
+
+    fn tone(sample_rate: u32, ms: u32) -> Vec<i16> {
+        let samples = (sample_rate * ms / 1000) as usize;
+        (0..samples)
+            .map(|i| {
+                let t = i as f32 / sample_rate as f32;
+                ((t * 440.0 * std::f32::consts::TAU).sin() * 8000.0) as i16
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_injected_tone_is_resampled_and_encoded() {
+        let codec_info = MediaNegotiator::codec_info_for_type(CodecType::PCMU);
+        let mut injector = PcmInjector::new(codec_info);
+
+        // 50 ms at 16 kHz -> two full 20 ms PCMU frames plus a 10 ms remainder
+        let frames = injector.push(&tone(16000, 50), 16000).unwrap();
+        assert_eq!(frames.len(), 2);
+        let tail = injector.flush().expect("remainder flushed");
+
+        let mut decoder = create_decoder(CodecType::PCMU);
+        let mut last: Option<(u32, u16)> = None;
+        for sample in frames.iter().chain(std::iter::once(&tail)) {
+            let MediaSample::Audio(frame) = sample else {
+                panic!("expected audio frame");
+            };
+            assert_eq!(frame.payload_type, Some(0));
+            assert_eq!(frame.clock_rate, 8000);
+            assert_eq!(frame.data.len(), 160);
+            if let Some((ts, seq)) = last {
+                assert_eq!(frame.rtp_timestamp, ts.wrapping_add(160));
+                assert_eq!(frame.sequence_number, Some(seq.wrapping_add(1)));
+            }
+            last = Some((frame.rtp_timestamp, frame.sequence_number.unwrap()));
+        }
+
+        let MediaSample::Audio(first) = &frames[0] else {
+            unreachable!()
+        };
+        let pcm = decoder.decode(&first.data);
+        let peak = pcm.iter().map(|s| s.unsigned_abs()).max().unwrap_or(0);
+        assert!(peak > 4000, "decoded tone should be audible, peak {peak}");
+    }
+
+    #[test]
+    fn test_injector_rejects_zero_sample_rate() {
+        let codec_info = MediaNegotiator::codec_info_for_type(CodecType::PCMA);
+        let mut injector = PcmInjector::new(codec_info);
+        assert!(injector.push(&[0; 160], 0).is_err());
+    }
+}
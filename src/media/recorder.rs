@@ -10,7 +10,7 @@ use std::collections::{BTreeMap, HashMap};
 use std::fs::File;
 use std::path::PathBuf;
 use std::time::{Duration, Instant};
-use tracing::debug;
+use tracing::{debug, warn};
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -22,6 +22,26 @@ pub struct RecorderOption {
     pub samplerate: u32,
     #[serde(default)]
     pub ptime: u32,
+    #[serde(default)]
+    pub codec: RecordingCodec,
+}
+
+/// Encoding of the samples stored in the recording WAV file.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum RecordingCodec {
+    #[default]
+    Pcmu,
+    Pcma,
+}
+
+impl RecordingCodec {
+    pub fn codec_type(self) -> CodecType {
+        match self {
+            RecordingCodec::Pcmu => CodecType::PCMU,
+            RecordingCodec::Pcma => CodecType::PCMA,
+        }
+    }
 }
 
 impl RecorderOption {
@@ -39,6 +59,7 @@ impl Default for RecorderOption {
             recorder_file: "".to_string(),
             samplerate: 16000,
             ptime: 200,
+            codec: RecordingCodec::default(),
         }
     }
 }
@@ -93,6 +114,9 @@ pub struct Recorder {
 
     written_samples: u64,
     writer: Box<dyn StreamWriter>,
+    /// While paused, incoming audio is replaced by silence of the same length
+    /// so the recording stays time-aligned with the call.
+    paused: bool,
 }
 
 impl Recorder {
@@ -151,9 +175,18 @@ impl Recorder {
             written_samples: 0,
             writer,
             ptime: Duration::from_millis(200),
+            paused: false,
         })
     }
 
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
     pub fn set_leg_profile(&mut self, leg: Leg, profile: NegotiatedLegProfile) {
         match leg {
             Leg::A => self.profile_a = profile,
@@ -195,6 +228,9 @@ impl Recorder {
         if let (Some(pt), Some(dpt)) = (frame.payload_type, dtmf_pt)
             && pt == dpt
         {
+            if self.paused {
+                return Ok(());
+            }
             return self.write_dtmf_payload(
                 leg,
                 &frame.data,
@@ -212,6 +248,17 @@ impl Recorder {
             Some(codec) => codec,
             None => CodecType::try_from(frame.payload_type.unwrap_or(0))?,
         };
+        if frame.raw_packet.is_none() && frame.clock_rate == 0 {
+            // Without a clock rate the timestamp can't be placed on the timeline
+            warn!(
+                recorder_path = %self.path,
+                leg = ?leg,
+                payload_type = ?frame.payload_type,
+                "Dropping recorder frame with zero clock rate"
+            );
+            crate::metrics::media::invalid_sample_rate("recorder");
+            return Ok(());
+        }
         let packet_ssrc = frame.raw_packet.as_ref().map(|packet| packet.header.ssrc);
         let (mut encoded, frame_clock_rate) = match sample {
             MediaSample::Audio(frame) => match frame.raw_packet.as_ref() {
@@ -219,7 +266,7 @@ impl Recorder {
                     Bytes::copy_from_slice(&packet.payload),
                     decoder_type.clock_rate().max(1),
                 ),
-                None => (frame.data.clone(), frame.clock_rate.max(1)),
+                None => (frame.data.clone(), frame.clock_rate),
             },
             _ => return Ok(()),
         };
@@ -247,6 +294,10 @@ impl Recorder {
             }
             .into();
         }
+        if self.paused {
+            let (_, bytes_per_block) = self.block_info();
+            encoded = self.get_silence_bytes(leg, encoded.len() / bytes_per_block)?;
+        }
         self.maybe_reset_leg_timeline(
             leg,
             frame.rtp_timestamp,
@@ -151,6 +151,142 @@ mod recorder_advanced_tests {
         let _ = std::fs::remove_file(&temp_path);
     }
 
+    #[test]
+    fn test_recorder_transcodes_to_configured_codec() {
+        use super::super::recorder::RecordingCodec;
+        use audio_codec::{create_decoder, create_encoder};
+
+        let temp_path = std::env::temp_dir().join("test_recorder_pcma_codec.wav");
+        let path_str = temp_path.to_str().unwrap();
+        let codec: RecordingCodec = serde_json::from_str("\"pcma\"").unwrap();
+        let mut recorder = Recorder::new(path_str, codec.codec_type()).unwrap();
+
+        // 1kHz tone, 20ms @ 8kHz, delivered as PCMU.
+        let tone: Vec<i16> = (0..160)
+            .map(|i| {
+                let t = i as f32 / 8000.0;
+                ((2.0 * std::f32::consts::PI * 1000.0 * t).sin() * 8000.0) as i16
+            })
+            .collect();
+        let frame = AudioFrame {
+            data: create_encoder(CodecType::PCMU).encode(&tone).into(),
+            rtp_timestamp: 0,
+            sequence_number: Some(1),
+            payload_type: Some(0),
+            clock_rate: 8000,
+            marker: false,
+            raw_packet: None,
+            source_addr: None,
+            header_extension: None,
+        };
+        recorder
+            .write_sample(Leg::A, &MediaSample::Audio(frame), None, None, None)
+            .unwrap();
+        recorder.finalize().unwrap();
+
+        let content = std::fs::read(&temp_path).unwrap();
+        let format_tag = u16::from_le_bytes([content[20], content[21]]);
+        assert_eq!(format_tag, 6, "recording should be A-law");
+
+        // Stereo interleave: leg A occupies the even bytes.
+        let leg_a: Vec<u8> = content[44..].iter().step_by(2).copied().collect();
+        assert_eq!(leg_a.len(), tone.len());
+        let decoded = create_decoder(CodecType::PCMA).decode(&leg_a);
+        for (expected, actual) in tone.iter().zip(decoded.iter()) {
+            let tolerance = (expected.unsigned_abs() / 8).max(64) as i32;
+            assert!(
+                (*expected as i32 - *actual as i32).abs() <= tolerance,
+                "decoded {} too far from {}",
+                actual,
+                expected
+            );
+        }
+
+        let _ = std::fs::remove_file(&temp_path);
+    }
+
+    #[test]
+    fn test_recorder_pause_inserts_time_aligned_silence() {
+        use audio_codec::{create_decoder, create_encoder};
+
+        let temp_path = std::env::temp_dir().join("test_recorder_pause_silence.wav");
+        let path_str = temp_path.to_str().unwrap();
+        let mut recorder = Recorder::new(path_str, CodecType::PCMU).unwrap();
+
+        let tone: Vec<i16> = (0..160)
+            .map(|i| {
+                let t = i as f32 / 8000.0;
+                ((2.0 * std::f32::consts::PI * 1000.0 * t).sin() * 8000.0) as i16
+            })
+            .collect();
+        let payload = create_encoder(CodecType::PCMU).encode(&tone);
+
+        // Ten 20 ms frames; frames 4..7 arrive while recording is paused.
+        for i in 0..10u32 {
+            recorder.set_paused((4..7).contains(&i));
+            let frame = AudioFrame {
+                data: payload.clone().into(),
+                rtp_timestamp: i * 160,
+                sequence_number: Some(i as u16 + 1),
+                payload_type: Some(0),
+                clock_rate: 8000,
+                marker: false,
+                raw_packet: None,
+                source_addr: None,
+                header_extension: None,
+            };
+            recorder
+                .write_sample(Leg::A, &MediaSample::Audio(frame), None, None, None)
+                .unwrap();
+        }
+        recorder.finalize().unwrap();
+
+        let content = std::fs::read(&temp_path).unwrap();
+        let leg_a: Vec<u8> = content[44..].iter().step_by(2).copied().collect();
+        assert_eq!(leg_a.len(), 1600, "paused frames must not be spliced out");
+        let decoded = create_decoder(CodecType::PCMU).decode(&leg_a);
+
+        let paused = &decoded[4 * 160..7 * 160];
+        assert!(
+            paused.iter().all(|s| s.abs() <= 8),
+            "paused window must be silence"
+        );
+        let before = &decoded[3 * 160..4 * 160];
+        let after = &decoded[7 * 160..8 * 160];
+        assert!(before.iter().any(|s| s.abs() > 1000));
+        assert!(after.iter().any(|s| s.abs() > 1000));
+
+        let _ = std::fs::remove_file(&temp_path);
+    }
+
+    #[test]
+    fn test_recorder_drops_frame_with_zero_clock_rate() {
+        let temp_path = std::env::temp_dir().join("test_recorder_zero_clock_rate.wav");
+        let path_str = temp_path.to_str().unwrap();
+        let mut recorder = Recorder::new(path_str, CodecType::PCMU).unwrap();
+
+        let frame = AudioFrame {
+            data: vec![0xFFu8; 160].into(),
+            rtp_timestamp: 160,
+            sequence_number: Some(1),
+            payload_type: Some(0),
+            clock_rate: 0,
+            marker: false,
+            raw_packet: None,
+            source_addr: None,
+            header_extension: None,
+        };
+        recorder
+            .write_sample(Leg::A, &MediaSample::Audio(frame), None, None, None)
+            .expect("zero clock rate frame should be skipped, not fail");
+        recorder.finalize().unwrap();
+
+        let metadata = std::fs::metadata(&temp_path).unwrap();
+        assert_eq!(metadata.len(), 44, "invalid frame must not be recorded");
+
+        let _ = std::fs::remove_file(&temp_path);
+    }
+
     // ==================== Recorder Dual-Leg Tests ====================
 
     #[test]
@@ -75,6 +75,14 @@ pub mod sip {
         )
         .record(duration_secs);
     }
+
+    pub fn locator_events_lagged(consumer: &str, missed: u64) {
+        metrics::counter!(
+            "rustpbx_sip_locator_events_lagged_total",
+            "consumer" => consumer.to_string()
+        )
+        .increment(missed);
+    }
 }
 
 pub mod trunk {
@@ -155,6 +163,31 @@ pub mod media {
         .set(count as f64);
     }
 
+    pub fn rtp_payload_rejected(direction: &str) {
+        metrics::counter!(
+            "rustpbx_rtp_payload_rejected_total",
+            "direction" => direction.to_string()
+        )
+        .increment(1);
+    }
+
+    pub fn invalid_sample_rate(component: &str) {
+        metrics::counter!(
+            "rustpbx_media_invalid_sample_rate_total",
+            "component" => component.to_string()
+        )
+        .increment(1);
+    }
+
+    pub fn set_rtp_port_utilization(active_calls: usize, capacity: usize) {
+        let ratio = if capacity == 0 {
+            1.0
+        } else {
+            active_calls as f64 / capacity as f64
+        };
+        metrics::gauge!("rustpbx_rtp_port_utilization_ratio").set(ratio);
+    }
+
     pub fn ice_connection_time_seconds(duration_secs: f64) {
         metrics::histogram!("rustpbx_webrtc_ice_connection_seconds").record(duration_secs);
     }
@@ -318,6 +351,16 @@ pub mod transcription {
     }
 }
 
+pub mod tts {
+    pub fn set_provider_status(provider: &str, healthy: bool) {
+        metrics::gauge!(
+            "rustpbx_tts_provider_up",
+            "provider" => provider.to_string()
+        )
+        .set(if healthy { 1.0 } else { 0.0 });
+    }
+}
+
 pub mod routing {
     pub fn route_evaluated(direction: &str, matched: bool) {
         metrics::counter!(
@@ -788,6 +831,7 @@ mod tests {
         media::ice_connection_time_seconds(0.5);
         media::webrtc_connection_created();
         media::webrtc_connection_failed("ice_timeout");
+        media::rtp_payload_rejected("rtp→webrtc");
 
         system::websocket_connection_created();
         system::websocket_connection_closed();
@@ -808,6 +852,8 @@ mod tests {
         transcription::latency_seconds(2.0, "zh");
         transcription::audio_duration_seconds(30.0, "zh");
 
+        tts::set_provider_status("http://localhost/tts", true);
+
         routing::route_evaluated("outbound", true);
         routing::default_route_used("outbound");
         routing::evaluation_latency_seconds(0.001);
@@ -1,3 +1,4 @@
+use crate::call::domain::MediaPathMode;
 use crate::proxy::proxy_call::sip_session::SipSessionHandle;
 use chrono::{DateTime, Utc};
 use serde::Serialize;
@@ -116,6 +117,23 @@ impl ActiveProxyCallRegistry {
         self.inner.lock().unwrap().entries.len()
     }
 
+    /// Active calls that may hold RTP ports. Calls whose session reports
+    /// media bypass are left out; calls without a snapshot yet are counted.
+    pub fn media_anchored_count(&self) -> usize {
+        let guard = self.inner.lock().unwrap();
+        guard
+            .entries
+            .keys()
+            .filter(|session_id| {
+                guard
+                    .handles
+                    .get(*session_id)
+                    .and_then(|handle| handle.snapshot())
+                    .is_none_or(|snapshot| snapshot.media_path != MediaPathMode::Bypass)
+            })
+            .count()
+    }
+
     pub fn list_recent(&self, limit: usize) -> Vec<ActiveProxyCallEntry> {
         let mut entries: Vec<_> = self
             .inner
@@ -282,6 +300,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_media_anchored_count_skips_bypass_calls() {
+        use crate::call::domain::SessionState;
+        use crate::proxy::proxy_call::sip_session::SessionSnapshot;
+
+        let registry = ActiveProxyCallRegistry::new();
+        for (session, media_path) in [
+            ("anchored", Some(MediaPathMode::Anchored)),
+            ("bypass", Some(MediaPathMode::Bypass)),
+            ("new", None),
+        ] {
+            let handle = make_handle(session);
+            if let Some(media_path) = media_path {
+                handle.update_snapshot(SessionSnapshot {
+                    id: session.into(),
+                    state: SessionState::Active,
+                    leg_count: 2,
+                    bridge_active: true,
+                    media_path,
+                    answer_sdp: None,
+                    callee_dialogs: Vec::new(),
+                });
+            }
+            registry.upsert(make_entry(session), handle);
+        }
+
+        assert_eq!(registry.count(), 3);
+        assert_eq!(registry.media_anchored_count(), 2);
+    }
+
     /// Single-trunk call: server dialog + callee dialog → both must be cleaned.
     #[test]
     fn test_single_trunk_call_no_leak() {
@@ -115,6 +115,26 @@ pub fn q850_reason_value(code: &rsipstack::sip::StatusCode, detail: Option<&str>
     )
 }
 
+/// Reject new calls once the RTP port pool can't carry another one. Only
+/// media-anchored calls hold ports, so bypass calls are not counted.
+fn check_rtp_port_admission(
+    rtp_config: &crate::config::RtpConfig,
+    anchored_calls: usize,
+) -> Result<(), RouteError> {
+    let Some(capacity) = rtp_config.call_capacity() else {
+        return Ok(());
+    };
+    crate::metrics::media::set_rtp_port_utilization(anchored_calls, capacity);
+    if anchored_calls >= capacity {
+        warn!(anchored_calls, capacity, "RTP port pool exhausted");
+        return Err(RouteError::from((
+            anyhow!("RTP port pool exhausted"),
+            Some(rsipstack::sip::StatusCode::ServiceUnavailable),
+        )));
+    }
+    Ok(())
+}
+
 #[async_trait]
 pub trait DialplanInspector: Send + Sync {
     async fn inspect_dialplan(
@@ -703,7 +723,7 @@ impl CallModule {
 
         if let Some(mut hints) = dialplan_hints {
             if let Some(enabled) = hints.enable_recording {
-                dialplan.recording.enabled = enabled;
+                dialplan.recording.set_override(enabled);
             }
             if let Some(bypass) = hints.bypass_media
                 && bypass
@@ -772,6 +792,8 @@ impl CallModule {
             Some(policy) if policy.enabled => policy,
             _ => return dialplan,
         };
+        // Applies to overridden and pre-configured recordings as well
+        dialplan.recording.pause_on_hold = policy.pause_on_hold.unwrap_or(false);
 
         if sipflow_active {
             dialplan.recording.enabled = false;
@@ -779,6 +801,10 @@ impl CallModule {
             return dialplan;
         }
 
+        if dialplan.recording.overridden && !dialplan.recording.enabled {
+            return dialplan;
+        }
+
         if dialplan.recording.enabled && dialplan.recording.option.is_some() {
             return dialplan;
         }
@@ -955,6 +981,9 @@ impl CallModule {
         if let Some(ptime) = policy.ptime {
             option.ptime = ptime;
         }
+        if let Some(codec) = policy.codec {
+            option.codec = codec;
+        }
         Some(option)
     }
 
@@ -1009,6 +1038,12 @@ impl CallModule {
         cookie: TransactionCookie,
         caller: &SipUser,
     ) -> Result<Dialplan, RouteError> {
+        let server = &self.inner.server;
+        check_rtp_port_admission(
+            &server.rtp_config,
+            server.active_call_registry.media_anchored_count(),
+        )?;
+
         let trunk_context = cookie.get_extension::<TrunkContext>();
         let source_trunk_hint = trunk_context.as_ref().map(|c| c.name.clone());
 
@@ -2309,6 +2344,50 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn recording_policy_respects_per_call_override() {
+        let (server, _) = create_test_server().await;
+        let config = Arc::new(ProxyConfig {
+            recording: Some(crate::config::RecordingPolicy {
+                enabled: true,
+                path: Some(std::env::temp_dir().to_string_lossy().to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        let module = CallModule::new(config, server);
+        let request = crate::proxy::tests::common::create_test_request(
+            rsipstack::sip::Method::Invite,
+            "bp",
+            None,
+            "rustpbx.com",
+            None,
+        );
+        let caller = SipUser {
+            username: "bp".to_string(),
+            realm: Some("rustpbx.com".to_string()),
+            ..Default::default()
+        };
+
+        let dialplan = Dialplan::new(
+            "rec-default".to_string(),
+            request.clone(),
+            DialDirection::Inbound,
+        );
+        let dialplan = module.apply_recording_policy(dialplan, &caller);
+        assert!(dialplan.recording.enabled);
+        assert!(dialplan.recording.option.is_some());
+
+        let mut dialplan = Dialplan::new("rec-off".to_string(), request, DialDirection::Inbound);
+        dialplan.recording.set_override(false);
+        let dialplan = module.apply_recording_policy(dialplan, &caller);
+        assert!(
+            !dialplan.recording.enabled,
+            "per-call disable must win over the global policy"
+        );
+        assert!(dialplan.recording.option.is_none());
+    }
+
     #[tokio::test]
     async fn default_resolve_sets_offline_marker_for_empty_locator() {
         let (server, config) = create_test_server().await;
@@ -2508,6 +2587,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_rtp_port_admission_rejects_when_pool_exhausted() {
+        let rtp_config = crate::config::RtpConfig {
+            start_port: Some(20000),
+            end_port: Some(20011),
+            ports_per_call: Some(4),
+            ..Default::default()
+        };
+        assert_eq!(rtp_config.call_capacity(), Some(3));
+
+        assert!(check_rtp_port_admission(&rtp_config, 2).is_ok());
+        let err = check_rtp_port_admission(&rtp_config, 3).unwrap_err();
+        assert_eq!(
+            err.status,
+            Some(rsipstack::sip::StatusCode::ServiceUnavailable)
+        );
+
+        // Without a per-call port budget there is no admission control
+        let unlimited = crate::config::RtpConfig {
+            ports_per_call: None,
+            ..rtp_config
+        };
+        assert!(check_rtp_port_admission(&unlimited, 1000).is_ok());
+    }
+
     #[test]
     fn test_parse_replaces_header_basic() {
         let mut request = rsipstack::sip::Request {
@@ -8,7 +8,7 @@ use super::ProxyAction;
 use crate::call::Location;
 use crate::call::TransactionCookie;
 use crate::proxy::ProxyModule;
-use crate::proxy::locator::LocatorEvent;
+use crate::proxy::locator::{LocatorEvent, recv_locator_event};
 use crate::proxy::presence::{PresenceManager, PresenceState, PresenceStatus};
 use anyhow::{Result, anyhow};
 use async_trait::async_trait;
@@ -212,18 +212,8 @@ impl ClusterEventHub {
         let mut rx = self.locator_events.subscribe();
         let this = self.clone();
         crate::utils::spawn(async move {
-            loop {
-                match rx.recv().await {
-                    Ok(event) => {
-                        this.dispatch_local_locator_event(event).await;
-                    }
-                    Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
-                        warn!("cluster event hub: lagged, missed {} locator events", n);
-                    }
-                    Err(tokio::sync::broadcast::error::RecvError::Closed) => {
-                        break;
-                    }
-                }
+            while let Some(event) = recv_locator_event(&mut rx, "cluster").await {
+                this.dispatch_local_locator_event(event).await;
             }
         });
     }
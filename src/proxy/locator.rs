@@ -17,8 +17,8 @@ use std::{
     sync::Arc,
     time::Instant,
 };
-use tokio::sync::Mutex;
-use tracing::debug;
+use tokio::sync::{Mutex, broadcast::error::RecvError};
+use tracing::{debug, warn};
 
 #[derive(Clone, Debug)]
 pub enum LocatorEvent {
@@ -33,6 +33,38 @@ pub type LocatorCreationFuture = Pin<Box<dyn Future<Output = Result<Box<dyn Loca
 pub type RealmChecker =
     Arc<dyn Fn(&str) -> Pin<Box<dyn Future<Output = bool> + Send>> + Send + Sync>;
 
+/// Receive the next locator event for `consumer`.
+///
+/// A subscriber that falls behind the broadcast buffer skips to the oldest
+/// retained event instead of stopping; the skipped events are logged and
+/// counted so `locator_event_capacity` can be sized. Returns `None` once all
+/// senders are dropped.
+pub async fn recv_locator_event(
+    rx: &mut LocatorEventReceiver,
+    consumer: &str,
+) -> Option<LocatorEvent> {
+    recv_locator_event_with_lag(rx, consumer).await.0
+}
+
+/// [`recv_locator_event`], also returning how many events were skipped.
+async fn recv_locator_event_with_lag(
+    rx: &mut LocatorEventReceiver,
+    consumer: &str,
+) -> (Option<LocatorEvent>, u64) {
+    let mut lagged = 0;
+    loop {
+        match rx.recv().await {
+            Ok(event) => return (Some(event), lagged),
+            Err(RecvError::Lagged(missed)) => {
+                warn!(consumer, missed, "locator event subscriber lagged");
+                crate::metrics::sip::locator_events_lagged(consumer, missed);
+                lagged += missed;
+            }
+            Err(RecvError::Closed) => return (None, lagged),
+        }
+    }
+}
+
 #[async_trait]
 pub trait Locator: Send + Sync {
     async fn get_identifier(&self, user: &str, realm: Option<&str>) -> String {
@@ -547,6 +579,27 @@ mod tests {
     use rsipstack::transport::SipAddr;
     use std::time::Duration;
 
+    #[tokio::test]
+    async fn recv_locator_event_counts_lagged_events() {
+        let (tx, mut rx) = tokio::sync::broadcast::channel(2);
+        for _ in 0..5 {
+            tx.send(LocatorEvent::Offline(Vec::new())).unwrap();
+        }
+
+        // Capacity 2 retains the last two events; the first three are lagged.
+        let (event, lagged) = recv_locator_event_with_lag(&mut rx, "test").await;
+        assert!(event.is_some());
+        assert_eq!(lagged, 3);
+        assert_eq!(
+            recv_locator_event_with_lag(&mut rx, "test").await.1,
+            0,
+            "no further events skipped"
+        );
+
+        drop(tx);
+        assert!(recv_locator_event(&mut rx, "test").await.is_none());
+    }
+
     #[tokio::test]
     async fn memory_locator_orders_by_last_modified() {
         let locator = MemoryLocator::new();
@@ -1,6 +1,6 @@
 use crate::call::Location;
 use crate::config::LocatorWebhookConfig;
-use crate::proxy::locator::{LocatorEvent, LocatorEventReceiver};
+use crate::proxy::locator::{LocatorEvent, LocatorEventReceiver, recv_locator_event};
 use serde::Serialize;
 use tracing::{debug, error, warn};
 
@@ -48,15 +48,8 @@ pub async fn handle_locator_webhook(config: LocatorWebhookConfig, mut rx: Locato
     debug!("locator webhook handler started for {}", config.url);
 
     loop {
-        let event = match rx.recv().await {
-            Ok(event) => event,
-            Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
-                warn!("locator webhook lagged, missed {} events", n);
-                continue;
-            }
-            Err(tokio::sync::broadcast::error::RecvError::Closed) => {
-                break;
-            }
+        let Some(event) = recv_locator_event(&mut rx, "webhook").await else {
+            break;
         };
 
         let (event_name, dto) = match event {
@@ -4,7 +4,7 @@ use crate::call::TransactionCookie;
 use crate::config::ProxyConfig;
 use crate::models::presence;
 use crate::proxy::cluster_event::EventSource;
-use crate::proxy::locator::LocatorEvent;
+use crate::proxy::locator::{LocatorEvent, recv_locator_event};
 use anyhow::{Result, anyhow};
 use async_trait::async_trait;
 use rsipstack::dialog::DialogId;
@@ -433,7 +433,7 @@ impl ProxyModule for PresenceModule {
         if let Some(mut rx) = self.server.locator_events.as_ref().map(|tx| tx.subscribe()) {
             crate::utils::spawn(async move {
                 let source = EventSource::Local;
-                while let Ok(event) = rx.recv().await {
+                while let Some(event) = recv_locator_event(&mut rx, "presence").await {
                     manager.handle_locator_event(event, &source).await;
                 }
             });
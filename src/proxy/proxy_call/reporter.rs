@@ -145,6 +145,10 @@ impl CallReporter {
             (None, None)
         };
 
+        let call_metadata = snapshot
+            .extensions
+            .get::<HashMap<String, String>>()
+            .cloned();
         let mut recorder = Vec::new();
 
         if self.context.dialplan.recording.enabled
@@ -158,7 +162,7 @@ impl CallReporter {
                 track_id: "mixed".to_string(),
                 path: recorder_config.recorder_file.clone(),
                 size,
-                extra: None,
+                extra: recording_extra(call_metadata.as_ref()),
             });
         }
         tracing::info!(
@@ -187,10 +191,7 @@ impl CallReporter {
             recording_url: recording_path_for_db,
             rewrite,
             last_error,
-            metadata: snapshot
-                .extensions
-                .get::<HashMap<String, String>>()
-                .cloned(),
+            metadata: call_metadata,
             ..Default::default()
         };
 
@@ -246,6 +247,17 @@ impl CallReporter {
     }
 }
 
+/// Per-call metadata stamped onto each recording entry.
+fn recording_extra(
+    metadata: Option<&HashMap<String, String>>,
+) -> Option<HashMap<String, serde_json::Value>> {
+    let metadata = metadata.filter(|metadata| !metadata.is_empty())?;
+    Some(HashMap::from([(
+        "metadata".to_string(),
+        serde_json::json!(metadata),
+    )]))
+}
+
 fn build_sip_leg_roles(snapshot: &CallSessionRecordSnapshot) -> HashMap<String, String> {
     let mut sip_leg_roles = HashMap::new();
     let caller_call_id = snapshot.server_dialog_id.call_id.clone();
@@ -424,6 +436,16 @@ mod tests {
         assert!(from.is_some() || from.is_none()); // Behavior depends on implementation
     }
 
+    #[test]
+    fn test_recording_extra_carries_call_metadata() {
+        let metadata = HashMap::from([("campaign_id".to_string(), "spring-24".to_string())]);
+        let extra = recording_extra(Some(&metadata)).unwrap();
+        assert_eq!(extra["metadata"]["campaign_id"], "spring-24");
+
+        assert!(recording_extra(None).is_none());
+        assert!(recording_extra(Some(&HashMap::new())).is_none());
+    }
+
     #[test]
     fn test_build_sip_leg_roles_uses_callee_call_ids() {
         let snapshot = CallSessionRecordSnapshot {
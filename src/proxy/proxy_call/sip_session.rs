@@ -140,6 +140,11 @@ pub struct SipSession {
     pub hangup_messages: Vec<SessionHangupMessage>,
     pub last_error: Option<(StatusCode, Option<String>)>,
     pub recording_state: Option<(String, Instant)>,
+    /// Legs currently holding the call via re-INVITE direction.
+    remote_hold_legs: HashSet<LegId>,
+    /// Set when the recorder was paused because of a remote hold, so only
+    /// that pause is lifted again on un-hold.
+    recording_paused_for_hold: bool,
 
     pub routed_caller: Option<String>,
     pub routed_callee: Option<String>,
@@ -363,6 +368,8 @@ impl SipSession {
             app_event_bridge: app_event_bridge.clone(),
         };
 
+        let initial = server_dialog.initial_request();
+
         // Build ApplicationContext for call apps (IVR, voicemail, etc.)
         let call_info = CallInfo {
             session_id: context.session_id.clone(),
@@ -370,6 +377,11 @@ impl SipSession {
             callee: context.original_callee.clone(),
             direction: context.dialplan.direction.to_string(),
             started_at: chrono::Utc::now(),
+            invite_headers: initial
+                .headers
+                .iter()
+                .map(|header| (header.name().to_string(), header.value().to_string()))
+                .collect(),
         };
         let app_ctx = ApplicationContext::new(
             server
@@ -427,7 +439,6 @@ impl SipSession {
             .with_factory(Arc::new(BuiltinAppFactory)),
         );
 
-        let initial = server_dialog.initial_request();
         let caller_offer = if initial.body().is_empty() {
             None
         } else {
@@ -480,6 +491,8 @@ impl SipSession {
             hangup_messages: Vec::new(),
             last_error: None,
             recording_state: None,
+            remote_hold_legs: HashSet::new(),
+            recording_paused_for_hold: false,
             routed_caller: None,
             routed_callee: None,
             routed_contact: None,
@@ -1171,13 +1184,9 @@ impl SipSession {
                 self.callee_answer_sdp = Some(target_answer_sdp.clone());
                 self.answer = Some(source_answer_sdp.clone());
                 if has_audio {
-                    self.update_leg_state(
-                        &LegId::from("caller"),
-                        if Self::is_hold_direction(offer_audio_direction.unwrap_or_default()) {
-                            LegState::Hold
-                        } else {
-                            LegState::Connected
-                        },
+                    self.apply_remote_hold(
+                        LegId::from("caller"),
+                        Self::is_hold_direction(offer_audio_direction.unwrap_or_default()),
                     );
                 }
             }
@@ -1185,13 +1194,9 @@ impl SipSession {
                 self.callee_offer = Some(source_answer_sdp.clone());
                 self.callee_answer_sdp = Some(source_answer_sdp.clone());
                 if has_audio {
-                    self.update_leg_state(
-                        &LegId::from("callee"),
-                        if Self::is_hold_direction(offer_audio_direction.unwrap_or_default()) {
-                            LegState::Hold
-                        } else {
-                            LegState::Connected
-                        },
+                    self.apply_remote_hold(
+                        LegId::from("callee"),
+                        Self::is_hold_direction(offer_audio_direction.unwrap_or_default()),
                     );
                 }
             }
@@ -1625,10 +1630,8 @@ impl SipSession {
                             reason: None,
                             event_type: crate::call::domain::ReferNotifyEventType::Notify,
                         };
-                        let subscribers = self.server.transfer_notify_subscribers.lock().await;
-                        for tx in subscribers.iter() {
-                            let _ = tx.send(event.clone());
-                        }
+                        let mut subscribers = self.server.transfer_notify_subscribers.lock().await;
+                        crate::call::domain::broadcast_refer_event(&mut subscribers, &event);
                         if (200..300).contains(&sip_status) {
                             self.hangup_reason
                                 .get_or_insert(CallRecordHangupReason::ByRefer);
@@ -1983,7 +1986,9 @@ impl SipSession {
         caller_is_webrtc: bool,
     ) -> Result<()> {
         let mut bridge_builder = BridgePeerBuilder::new(format!("{}-app-bridge", self.id))
-            .with_enable_latching(self.server.proxy_config.enable_latching);
+            .with_enable_latching(self.server.proxy_config.enable_latching)
+            .with_rtp_dedup_window(self.server.proxy_config.rtp_dedup_window)
+            .with_max_rtp_payload(self.server.proxy_config.max_rtp_payload);
 
         if let (Some(start), Some(end)) = (
             self.server.rtp_config.start_port,
@@ -3936,7 +3941,9 @@ impl SipSession {
         if need_transport_bridge {
             self.callee_offer_uses_media_bridge = true;
             let mut bridge_builder = BridgePeerBuilder::new(format!("{}-bridge", self.id))
-                .with_enable_latching(self.server.proxy_config.enable_latching);
+                .with_enable_latching(self.server.proxy_config.enable_latching)
+                .with_rtp_dedup_window(self.server.proxy_config.rtp_dedup_window)
+                .with_max_rtp_payload(self.server.proxy_config.max_rtp_payload);
             if let (Some(start), Some(end)) = (
                 self.server.rtp_config.start_port,
                 self.server.rtp_config.end_port,
@@ -4386,6 +4393,45 @@ impl SipSession {
         !matches!(direction, rustrtc::Direction::SendRecv)
     }
 
+    /// Record a hold or un-hold signalled by a re-INVITE on `leg_id` and,
+    /// unless the recording policy keeps hold audio, pause the recorder while
+    /// any leg is held so the file carries silence instead.
+    fn apply_remote_hold(&mut self, leg_id: LegId, held: bool) {
+        self.update_leg_state(
+            &leg_id,
+            if held {
+                LegState::Hold
+            } else {
+                LegState::Connected
+            },
+        );
+        if held {
+            self.remote_hold_legs.insert(leg_id);
+        } else {
+            self.remote_hold_legs.remove(&leg_id);
+        }
+
+        if !self.context.dialplan.recording.pause_on_hold || self.recording_state.is_none() {
+            return;
+        }
+        let mut guard = self.recorder.write();
+        let Some(recorder) = guard.as_mut() else {
+            return;
+        };
+        if !self.remote_hold_legs.is_empty() {
+            // Leave a manual pause alone so un-hold doesn't resume it
+            if !recorder.is_paused() {
+                recorder.set_paused(true);
+                self.recording_paused_for_hold = true;
+                info!("Recording paused for remote hold");
+            }
+        } else if self.recording_paused_for_hold {
+            recorder.set_paused(false);
+            self.recording_paused_for_hold = false;
+            info!("Recording resumed after remote hold");
+        }
+    }
+
     async fn get_local_reinvite_pc(&self, side: DialogSide) -> Option<rustrtc::PeerConnection> {
         if let Some(bridge) = &self.media_bridge {
             let leg_is_webrtc = match side {
@@ -4548,13 +4594,9 @@ impl SipSession {
                 self.caller_offer = Some(offer_sdp.to_string());
                 self.answer = Some(answer_sdp.clone());
                 if has_audio {
-                    self.update_leg_state(
-                        &LegId::from("caller"),
-                        if Self::is_hold_direction(offer_direction.unwrap_or_default()) {
-                            LegState::Hold
-                        } else {
-                            LegState::Connected
-                        },
+                    self.apply_remote_hold(
+                        LegId::from("caller"),
+                        Self::is_hold_direction(offer_direction.unwrap_or_default()),
                     );
                 }
             }
@@ -4562,13 +4604,9 @@ impl SipSession {
                 self.callee_offer = Some(answer_sdp.clone());
                 self.callee_answer_sdp = Some(answer_sdp.clone());
                 if has_audio {
-                    self.update_leg_state(
-                        &LegId::from("callee"),
-                        if Self::is_hold_direction(offer_direction.unwrap_or_default()) {
-                            LegState::Hold
-                        } else {
-                            LegState::Connected
-                        },
+                    self.apply_remote_hold(
+                        LegId::from("callee"),
+                        Self::is_hold_direction(offer_direction.unwrap_or_default()),
                     );
                 }
             }
@@ -4808,7 +4846,15 @@ impl SipSession {
                 "Live recording is disabled when SipFlow is enabled"
             ));
         }
-        let mut recorder = Recorder::new(path, CodecType::PCMU)?;
+        let codec = self
+            .context
+            .dialplan
+            .recording
+            .option
+            .as_ref()
+            .map(|option| option.codec.codec_type())
+            .unwrap_or(CodecType::PCMU);
+        let mut recorder = Recorder::new(path, codec)?;
         if let Some(forwarding) =
             Self::get_forwarding_track(&self.caller_peer, Self::CALLER_FORWARDING_TRACK_ID).await
         {
@@ -4848,6 +4894,10 @@ impl SipSession {
         if self.recording_state.is_none() {
             return Err(anyhow!("Recording not active"));
         }
+        if let Some(recorder) = self.recorder.write().as_mut() {
+            recorder.set_paused(true);
+        }
+        self.recording_paused_for_hold = false;
         info!("Recording paused");
         Ok(())
     }
@@ -4856,6 +4906,10 @@ impl SipSession {
         if self.recording_state.is_none() {
             return Err(anyhow!("Recording not active"));
         }
+        if let Some(recorder) = self.recorder.write().as_mut() {
+            recorder.set_paused(false);
+        }
+        self.recording_paused_for_hold = false;
         info!("Recording resumed");
         Ok(())
     }
@@ -5578,6 +5632,19 @@ impl SipSession {
                 Err(e) => CommandResult::failure(e.to_string()),
             },
 
+            CallCommand::InjectPcm {
+                leg_id,
+                pcm,
+                sample_rate,
+                flush,
+            } => match self
+                .handle_inject_pcm(leg_id, &pcm, sample_rate, flush)
+                .await
+            {
+                Ok(_) => CommandResult::success(),
+                Err(e) => CommandResult::failure(e.to_string()),
+            },
+
             CallCommand::StartRecording { config } => {
                 match self
                     .start_recording(
@@ -6461,6 +6528,40 @@ impl SipSession {
         Ok(())
     }
 
+    async fn handle_inject_pcm(
+        &mut self,
+        leg_id: Option<LegId>,
+        pcm: &[i16],
+        sample_rate: u32,
+        flush: bool,
+    ) -> Result<()> {
+        if leg_id
+            .as_ref()
+            .is_some_and(|lid| lid != &LegId::from("caller"))
+        {
+            return Err(anyhow!("PCM injection is only supported on the caller leg"));
+        }
+        if !self.caller_answer_uses_media_bridge {
+            return Err(anyhow!(
+                "PCM injection requires media bridge for caller leg"
+            ));
+        }
+        let bridge = self
+            .media_bridge
+            .clone()
+            .ok_or_else(|| anyhow!("PCM injection requires active media bridge"))?;
+        bridge
+            .inject_pcm(
+                self.leg_bridge_endpoint(&LegId::from("caller")),
+                self.caller_output_codec_info(),
+                pcm,
+                sample_rate,
+                flush,
+            )
+            .await?;
+        Ok(())
+    }
+
     async fn handle_stop_playback(&mut self, leg_id: Option<LegId>) -> Result<()> {
         let to_stop: Vec<String> = match leg_id {
             None => self.playback_tracks.keys().cloned().collect(),
@@ -7656,6 +7757,97 @@ mod tests {
         assert_eq!(callee_peer.get_tracks_call_count(), 0);
     }
 
+    #[tokio::test]
+    async fn test_remote_hold_pauses_and_resumes_recording() {
+        use crate::call::{DialDirection, Dialplan, TransactionCookie};
+        use crate::proxy::proxy_call::test_util::tests::MockMediaPeer;
+        use crate::proxy::tests::common::{
+            create_test_request, create_test_server, create_transaction,
+        };
+
+        let (server, _) = create_test_server().await;
+        let request = create_test_request(
+            rsipstack::sip::Method::Invite,
+            "alice",
+            None,
+            "rustpbx.com",
+            None,
+        );
+        let original_request = request.clone();
+        let (tx, _) = create_transaction(request).await;
+        let (state_tx, _state_rx) = mpsc::unbounded_channel();
+        let server_dialog = server
+            .dialog_layer
+            .get_or_create_server_invite(&tx, state_tx, None, None)
+            .expect("failed to create server dialog");
+
+        let context = CallContext {
+            session_id: "test-session".to_string(),
+            dialplan: Arc::new(Dialplan::new(
+                "test-session".to_string(),
+                original_request.clone(),
+                DialDirection::Inbound,
+            )),
+            cookie: TransactionCookie::default(),
+            start_time: Instant::now(),
+            original_caller: "sip:alice@rustpbx.com".to_string(),
+            original_callee: "sip:bob@rustpbx.com".to_string(),
+            max_forwards: 70,
+            dtmf_digits: Vec::new(),
+        };
+
+        let (mut session, _handle, _cmd_rx) = SipSession::new(
+            server.clone(),
+            CancellationToken::new(),
+            None,
+            context,
+            server_dialog,
+            false,
+            Arc::new(MockMediaPeer::new()),
+            Arc::new(MockMediaPeer::new()),
+        );
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hold.wav");
+        let path = path.to_str().unwrap();
+        *session.recorder.write() = Some(Recorder::new(path, CodecType::PCMU).unwrap());
+        session.recording_state = Some((path.to_string(), Instant::now()));
+        let is_paused =
+            |session: &SipSession| session.recorder.read().as_ref().unwrap().is_paused();
+
+        // Hold audio is recorded unless pause_on_hold is set
+        session.apply_remote_hold(LegId::from("caller"), true);
+        assert!(!is_paused(&session), "hold must not pause by default");
+        session.apply_remote_hold(LegId::from("caller"), false);
+
+        let mut dialplan = Dialplan::new(
+            "test-session".to_string(),
+            original_request,
+            DialDirection::Inbound,
+        );
+        dialplan.recording.pause_on_hold = true;
+        session.context.dialplan = Arc::new(dialplan);
+
+        // Caller sends sendonly, then sendrecv again
+        session.apply_remote_hold(LegId::from("caller"), true);
+        assert!(is_paused(&session), "hold should pause the recorder");
+        assert_eq!(
+            session
+                .legs
+                .get(&LegId::from("caller"))
+                .map(|leg| leg.state),
+            Some(LegState::Hold)
+        );
+        session.apply_remote_hold(LegId::from("caller"), false);
+        assert!(!is_paused(&session), "un-hold should resume the recorder");
+
+        // A pause requested by the user outlives the hold
+        session.pause_recording().await.unwrap();
+        session.apply_remote_hold(LegId::from("callee"), true);
+        session.apply_remote_hold(LegId::from("callee"), false);
+        assert!(is_paused(&session), "manual pause must survive un-hold");
+    }
+
     #[tokio::test]
     async fn test_prepare_app_caller_media_bridge_routes_playback_through_bridge() {
         use crate::call::{DialDirection, Dialplan, TransactionCookie};
@@ -444,10 +444,8 @@ impl SipSession {
             reason,
             event_type,
         };
-        let subscribers = self.server.transfer_notify_subscribers.lock().await;
-        for tx in subscribers.iter() {
-            let _ = tx.send(event.clone());
-        }
+        let mut subscribers = self.server.transfer_notify_subscribers.lock().await;
+        crate::call::domain::broadcast_refer_event(&mut subscribers, &event);
     }
 
     pub(super) async fn handle_transfer_complete(&mut self, consult_leg: LegId) -> Result<()> {
@@ -292,7 +292,7 @@ impl CallRouter for HttpCallRouter {
                 dialplan = dialplan.with_targets(strategy);
 
                 if let Some(record) = result.record {
-                    dialplan.recording.enabled = record;
+                    dialplan.recording.set_override(record);
                 }
 
                 if let Some(mode) = result.media_proxy {
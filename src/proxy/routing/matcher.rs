@@ -1159,9 +1159,37 @@ pub(crate) fn apply_trunk_config(option: &mut InviteOption, trunk: &TrunkConfig)
         None
     };
 
+    // Route through the outbound proxy, leaving the request URI on the trunk
+    let (next_hop, route_header, transport) = if let Some(proxy) = &trunk.next_hop_proxy {
+        let mut proxy_uri: rsipstack::sip::Uri = proxy
+            .as_str()
+            .try_into()
+            .map_err(|e| anyhow!("Invalid trunk next-hop proxy '{}': {:?}", proxy, e))?;
+        // The proxy's own transport param decides how to reach it
+        let transport = proxy_uri
+            .params
+            .iter()
+            .find_map(|param| match param {
+                rsipstack::sip::Param::Transport(t) => Some(*t),
+                _ => None,
+            })
+            .or(transport);
+        if !proxy_uri
+            .params
+            .iter()
+            .any(|param| matches!(param, rsipstack::sip::Param::Lr))
+        {
+            proxy_uri.params.push(rsipstack::sip::Param::Lr);
+        }
+        let route = rsipstack::sip::Header::Other("Route".to_string(), format!("<{}>", proxy_uri));
+        (proxy_uri.host_with_port, Some(route), transport)
+    } else {
+        (dest_uri.host_with_port.clone(), None, transport)
+    };
+
     option.destination = Some(SipAddr {
         r#type: transport,
-        addr: dest_uri.host_with_port.clone(),
+        addr: next_hop,
     });
 
     // Save original caller before potential rewrite for P-Asserted-Identity header
@@ -1188,6 +1216,10 @@ pub(crate) fn apply_trunk_config(option: &mut InviteOption, trunk: &TrunkConfig)
 
     let headers = option.headers.as_mut().unwrap();
 
+    if let Some(route) = route_header {
+        headers.push(route);
+    }
+
     // Add P-Asserted-Identity header (using original caller, not rewritten)
     if trunk.username.is_some() {
         let pai_header = rsipstack::sip::Header::Other(
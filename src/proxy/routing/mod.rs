@@ -57,6 +57,12 @@ pub struct TrunkConfig {
     pub weight: Option<u32>,
     #[serde(default)]
     pub transport: Option<String>,
+    /// Next-hop proxy URI for outbound requests. When set, INVITEs are sent
+    /// to this address with a loose `Route` header, while the request URI
+    /// still targets `dest`. Unrelated to the console trunk's
+    /// `outbound_proxy` column, which is used as `dest`/`backup_dest`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_hop_proxy: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub id: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -188,6 +194,7 @@ impl Default for TrunkConfig {
             max_cps: None,
             weight: None,
             transport: None,
+            next_hop_proxy: None,
             id: None,
             direction: None,
             inbound_hosts: Vec::new(),
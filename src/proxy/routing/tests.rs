@@ -1735,6 +1735,86 @@ async fn test_apply_trunk_config_rewrites_callee_host() {
     assert_eq!(cred.password, "pass");
 }
 
+#[tokio::test]
+async fn test_apply_trunk_config_routes_via_next_hop_proxy() {
+    use crate::proxy::routing::matcher::apply_trunk_config;
+
+    let mut option = create_invite_option(
+        "sip:alice@rustpbx.com",
+        "sip:1001@rustpbx.com",
+        None,
+        Some("application/sdp"),
+        None,
+    );
+
+    let trunk = TrunkConfig {
+        dest: "sip:carrier.gateway.com:5060".to_string(),
+        next_hop_proxy: Some("sip:edge.proxy.local:5070".to_string()),
+        ..Default::default()
+    };
+
+    apply_trunk_config(&mut option, &trunk).unwrap();
+
+    // Request URI still targets the trunk
+    assert_eq!(option.callee.host().to_string(), "carrier.gateway.com");
+
+    // Packets go to the proxy
+    let dest = option.destination.expect("destination should be set");
+    assert_eq!(dest.addr.host.to_string(), "edge.proxy.local");
+    assert_eq!(dest.addr.port, Some(5070.into()));
+
+    let route = option
+        .headers
+        .unwrap()
+        .into_iter()
+        .find_map(|h| match h {
+            rsipstack::sip::Header::Other(name, value) if name == "Route" => Some(value),
+            _ => None,
+        })
+        .expect("Route header should be added");
+    assert_eq!(route, "<sip:edge.proxy.local:5070;lr>");
+}
+
+#[tokio::test]
+async fn test_apply_trunk_config_next_hop_proxy_keeps_uri_params() {
+    use crate::proxy::routing::matcher::apply_trunk_config;
+
+    let mut option = create_invite_option(
+        "sip:alice@rustpbx.com",
+        "sip:1001@rustpbx.com",
+        None,
+        Some("application/sdp"),
+        None,
+    );
+
+    let trunk = TrunkConfig {
+        dest: "sip:carrier.gateway.com:5060".to_string(),
+        next_hop_proxy: Some("sip:edge.proxy.local:5071;transport=tcp;lr".to_string()),
+        ..Default::default()
+    };
+
+    apply_trunk_config(&mut option, &trunk).unwrap();
+
+    let dest = option.destination.expect("destination should be set");
+    assert_eq!(dest.r#type, Some(rsipstack::sip::transport::Transport::Tcp));
+
+    let route = option
+        .headers
+        .unwrap()
+        .into_iter()
+        .find_map(|h| match h {
+            rsipstack::sip::Header::Other(name, value) if name == "Route" => Some(value),
+            _ => None,
+        })
+        .expect("Route header should be added");
+    assert!(
+        route.to_ascii_lowercase().contains("transport=tcp"),
+        "{}",
+        route
+    );
+    assert_eq!(route.matches("lr").count(), 1, "{}", route);
+}
+
 #[tokio::test]
 async fn test_apply_trunk_config_with_ipv6_dest() {
     use crate::proxy::routing::matcher::apply_trunk_config;
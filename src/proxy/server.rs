@@ -579,7 +579,8 @@ impl SipServerBuilder {
             );
 
         let locator_events = self.locator_events.unwrap_or_else(|| {
-            let (tx, _) = tokio::sync::broadcast::channel(12);
+            let (tx, _) =
+                tokio::sync::broadcast::channel(self.config.locator_event_capacity.max(1));
             tx
         });
 
@@ -663,7 +664,10 @@ impl SipServerBuilder {
         let queue_manager = Arc::new(crate::call::runtime::QueueManager::new());
 
         // Create conference manager with in-server audio mixing
-        let conference_manager = Arc::new(crate::call::runtime::ConferenceManager::new());
+        let conference_manager = Arc::new(
+            crate::call::runtime::ConferenceManager::new()
+                .with_limiter(self.config.conference_limiter),
+        );
 
         let inner = Arc::new(SipServerInner {
             rtp_config,
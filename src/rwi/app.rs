@@ -1,10 +1,13 @@
 use crate::call::RouteContext;
 use crate::call::app::{AppAction, AppEvent, ApplicationContext, CallApp, CallAppType};
 use crate::call::app::{CallController, ExitReason};
+use crate::call::runtime::{AppFactory, register_call_app};
+use crate::rwi::auth::RwiConfig;
 use crate::rwi::gateway::{RwiGateway, SessionId};
 use crate::rwi::proto::RwiEvent;
 use crate::rwi::session::OwnershipMode;
 use async_trait::async_trait;
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -13,26 +16,43 @@ pub const RWI_APP_NAME: &str = "rwi";
 #[derive(Clone)]
 pub struct RwiAddon {
     gateway: Arc<RwLock<RwiGateway>>,
+    sip_header_passthrough: Vec<String>,
 }
 
 impl RwiAddon {
     pub fn new(gateway: Arc<RwLock<RwiGateway>>) -> Self {
-        Self { gateway }
+        Self {
+            gateway,
+            sip_header_passthrough: Vec::new(),
+        }
     }
-}
 
-#[async_trait]
-impl crate::call::CallAppFactory for RwiAddon {
-    async fn create_app(
+    /// Addon configured from the `[rwi]` section.
+    pub fn from_config(gateway: Arc<RwLock<RwiGateway>>, config: &RwiConfig) -> Self {
+        Self::new(gateway).with_sip_header_passthrough(config.sip_header_passthrough.clone())
+    }
+
+    /// Allowlist of INVITE headers reported in `CallIncoming`.
+    pub fn with_sip_header_passthrough(mut self, headers: Vec<String>) -> Self {
+        self.sip_header_passthrough = headers;
+        self
+    }
+
+    /// Make the `rwi` call app available to every session.
+    pub fn register(self) {
+        register_call_app(
+            RWI_APP_NAME,
+            Arc::new(move |app_name, params, context| {
+                AppFactory::create_app(&self, app_name, params, context)
+            }),
+        );
+    }
+
+    fn build_app<'a>(
         &self,
-        app_name: &str,
-        _context: &RouteContext<'_>,
         params: &serde_json::Value,
-    ) -> Option<Box<dyn CallApp>> {
-        if app_name != RWI_APP_NAME {
-            return None;
-        }
-
+        headers: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Box<dyn CallApp> {
         let context_name = params
             .get("context")
             .and_then(|v| v.as_str())
@@ -44,14 +64,74 @@ impl crate::call::CallAppFactory for RwiAddon {
             .and_then(|v| v.as_str())
             .map(String::from);
 
-        Some(Box::new(RwiApp::new(
-            context_name,
-            session_id,
-            self.gateway.clone(),
-        )))
+        let sip_headers = collect_sip_headers(headers, &self.sip_header_passthrough);
+
+        Box::new(
+            RwiApp::new(context_name, session_id, self.gateway.clone())
+                .with_sip_headers(sip_headers),
+        )
     }
 }
 
+#[async_trait]
+impl crate::call::CallAppFactory for RwiAddon {
+    async fn create_app(
+        &self,
+        app_name: &str,
+        context: &RouteContext<'_>,
+        params: &serde_json::Value,
+    ) -> Option<Box<dyn CallApp>> {
+        if app_name != RWI_APP_NAME {
+            return None;
+        }
+        let headers = context
+            .original_request
+            .headers
+            .iter()
+            .map(|header| (header.name(), header.value()));
+        Some(self.build_app(params, headers))
+    }
+}
+
+impl AppFactory for RwiAddon {
+    fn create_app(
+        &self,
+        app_name: &str,
+        params: Option<serde_json::Value>,
+        context: &ApplicationContext,
+    ) -> Option<Box<dyn CallApp>> {
+        if app_name != RWI_APP_NAME {
+            return None;
+        }
+        let headers = context
+            .call_info
+            .invite_headers
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()));
+        Some(self.build_app(&params.unwrap_or_default(), headers))
+    }
+}
+
+/// Pick the allowlisted headers (case-insensitive) out of the INVITE, keyed by
+/// the name as configured. The first occurrence of a header wins.
+pub fn collect_sip_headers<'a>(
+    headers: impl IntoIterator<Item = (&'a str, &'a str)>,
+    allowlist: &[String],
+) -> HashMap<String, String> {
+    let mut collected = HashMap::new();
+    for (name, value) in headers {
+        if let Some(configured) = allowlist
+            .iter()
+            .find(|configured| configured.eq_ignore_ascii_case(name))
+        {
+            collected
+                .entry(configured.clone())
+                .or_insert_with(|| value.trim().to_string());
+        }
+    }
+    collected
+}
+
 pub struct RwiApp {
     context_name: String,
     session_id: Option<SessionId>,
@@ -63,6 +143,8 @@ pub struct RwiApp {
     current_track_id: Option<String>,
     /// If `true`, the next DTMF digit will interrupt the current playback.
     interrupt_on_dtmf: bool,
+    /// Allowlisted INVITE headers reported in `CallIncoming`.
+    sip_headers: HashMap<String, String>,
 }
 
 impl RwiApp {
@@ -79,9 +161,15 @@ impl RwiApp {
             owned_call_id: None,
             current_track_id: None,
             interrupt_on_dtmf: false,
+            sip_headers: HashMap::new(),
         }
     }
 
+    pub fn with_sip_headers(mut self, sip_headers: HashMap<String, String>) -> Self {
+        self.sip_headers = sip_headers;
+        self
+    }
+
     async fn send_event(&self, event: RwiEvent) {
         let gw = self.gateway.read().await;
         if let Some(session_id) = &self.session_id {
@@ -121,7 +209,7 @@ impl CallApp for RwiApp {
                 callee: context.call_info.callee.clone(),
                 direction: context.call_info.direction.clone(),
                 trunk: None,
-                sip_headers: std::collections::HashMap::new(),
+                sip_headers: self.sip_headers.clone(),
             },
         ))
         .await;
@@ -728,4 +816,127 @@ mod tests {
             "no CallAnswered should be emitted when there is no session_id"
         );
     }
+
+    #[tokio::test]
+    async fn test_create_app_reports_allowlisted_sip_headers() {
+        let gateway = create_test_gateway();
+        let (event_tx, mut event_rx) = tokio::sync::mpsc::unbounded_channel();
+        {
+            let mut gw = gateway.write().await;
+            let identity = RwiIdentity {
+                token: "sub".into(),
+                scopes: vec![],
+            };
+            let session = gw.create_session(identity);
+            let sid = session.read().await.id.clone();
+            gw.set_session_event_sender(&sid, event_tx);
+            gw.subscribe(&sid, vec!["ctx-hdr".to_string()]).await;
+        }
+
+        let request = rsipstack::sip::Request {
+            method: rsipstack::sip::Method::Invite,
+            uri: "sip:2000@example.com".try_into().unwrap(),
+            headers: vec![
+                rsipstack::sip::Header::From("sip:1001@example.com".into()),
+                rsipstack::sip::Header::To("sip:2000@example.com".into()),
+                rsipstack::sip::Header::Other("X-Account-Id".into(), "acct-42".into()),
+                rsipstack::sip::Header::Other("X-Internal".into(), "secret".into()),
+            ]
+            .into(),
+            version: rsipstack::sip::Version::V2,
+            body: vec![],
+        };
+        let captures = HashMap::new();
+        let route_context = RouteContext {
+            caller: "sip:1001@example.com".try_into().unwrap(),
+            callee: "sip:2000@example.com".try_into().unwrap(),
+            original_request: &request,
+            captures: &captures,
+        };
+
+        let addon = RwiAddon::new(gateway.clone())
+            .with_sip_header_passthrough(vec!["x-account-id".to_string(), "X-Missing".to_string()]);
+        let app = crate::call::CallAppFactory::create_app(
+            &addon,
+            RWI_APP_NAME,
+            &route_context,
+            &serde_json::json!({ "context": "ctx-hdr" }),
+        )
+        .await
+        .expect("rwi app");
+
+        let _stack = crate::call::app::testing::MockCallStack::run(app, "1001", "2000");
+
+        let ev = tokio::time::timeout(std::time::Duration::from_secs(1), event_rx.recv())
+            .await
+            .expect("CallIncoming should arrive")
+            .expect("channel open");
+        let ev = serde_json::to_value(&ev).unwrap();
+        let ev_str = ev.to_string();
+        assert!(ev_str.contains("acct-42"), "missing header: {ev_str}");
+        assert!(
+            ev_str.contains("x-account-id"),
+            "missing header name: {ev_str}"
+        );
+        assert!(!ev_str.contains("secret"), "leaked header: {ev_str}");
+        assert!(
+            !ev_str.contains("X-Missing"),
+            "absent header reported: {ev_str}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_registered_app_reports_configured_sip_headers() {
+        use crate::call::app::CallInfo;
+
+        let gateway = create_test_gateway();
+        let (event_tx, mut event_rx) = tokio::sync::mpsc::unbounded_channel();
+        {
+            let mut gw = gateway.write().await;
+            let identity = RwiIdentity {
+                token: "sub".into(),
+                scopes: vec![],
+            };
+            let session = gw.create_session(identity);
+            let sid = session.read().await.id.clone();
+            gw.set_session_event_sender(&sid, event_tx);
+            gw.subscribe(&sid, vec!["ctx-cfg".to_string()]).await;
+        }
+
+        let config: RwiConfig =
+            toml::from_str(r#"sip_header_passthrough = ["X-Tenant-ID"]"#).unwrap();
+        RwiAddon::from_config(gateway, &config).register();
+
+        let context = ApplicationContext::new(
+            sea_orm::DatabaseConnection::Disconnected,
+            CallInfo {
+                session_id: "call-cfg".into(),
+                caller: "sip:1001@example.com".into(),
+                callee: "sip:2000@example.com".into(),
+                direction: "inbound".into(),
+                started_at: chrono::Utc::now(),
+                invite_headers: vec![
+                    ("x-tenant-id".into(), "tenant-7".into()),
+                    ("X-Internal".into(), "secret".into()),
+                ],
+            },
+            Arc::new(crate::config::Config::default()),
+        );
+        let app = crate::call::runtime::lookup_custom_app(
+            RWI_APP_NAME,
+            Some(serde_json::json!({ "context": "ctx-cfg" })),
+            &context,
+        )
+        .expect("rwi app registered");
+
+        let _stack = crate::call::app::testing::MockCallStack::run_with_context(app, context);
+
+        let ev = tokio::time::timeout(std::time::Duration::from_secs(1), event_rx.recv())
+            .await
+            .expect("CallIncoming should arrive")
+            .expect("channel open");
+        let ev = serde_json::to_value(&ev).unwrap().to_string();
+        assert!(ev.contains(r#""X-Tenant-ID":"tenant-7""#), "{ev}");
+        assert!(!ev.contains("secret"), "leaked header: {ev}");
+    }
 }
@@ -89,6 +89,12 @@ pub struct RwiConfig {
     pub contexts: Vec<RwiContextConfig>,
     #[serde(default)]
     pub transfer: TransferConfig,
+    /// INVITE headers copied into `call.incoming` `sip_headers` (case-insensitive).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sip_header_passthrough: Vec<String>,
+    /// Record every gateway call event to this JSON-lines file for replay.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_capture_path: Option<String>,
 }
 
 impl Default for RwiConfig {
@@ -102,6 +108,8 @@ impl Default for RwiConfig {
             tokens: Vec::new(),
             contexts: Vec::new(),
             transfer: TransferConfig::default(),
+            sip_header_passthrough: Vec::new(),
+            event_capture_path: None,
         }
     }
 }
@@ -224,6 +232,8 @@ mod tests {
                 },
             ],
             transfer: TransferConfig::default(),
+            sip_header_passthrough: Vec::new(),
+            event_capture_path: None,
         }
     }
 
@@ -1,5 +1,5 @@
 use crate::rwi::auth::RwiIdentity;
-use crate::rwi::proto::RwiEvent;
+use crate::rwi::proto::{RwiEvent, RwiEventTx};
 use crate::rwi::session::{OwnershipMode, RwiSession, SupervisorMode};
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::{Arc, Arc as StdArc, Mutex};
@@ -42,6 +42,8 @@ pub struct RwiGateway {
     event_cache: Mutex<EventCacheState>,
     max_cache_size: usize,
     max_cache_age_secs: u64,
+    /// Receives a copy of every cached call event, e.g. an event recorder.
+    event_capture: Option<RwiEventTx>,
 }
 
 #[derive(Debug)]
@@ -80,9 +82,16 @@ impl RwiGateway {
             }),
             max_cache_size,
             max_cache_age_secs,
+            event_capture: None,
         }
     }
 
+    /// Mirror every call event dispatched through the gateway to `tx`.
+    /// See [`crate::rwi::replay::RwiEventRecorder::spawn`].
+    pub fn set_event_capture(&mut self, tx: RwiEventTx) {
+        self.event_capture = Some(tx);
+    }
+
     /// Create a new RWI session and return the Arc handle.
     /// The caller must call [`set_session_event_sender`] with the WS sender after this.
     pub fn create_session(&mut self, identity: RwiIdentity) -> Arc<RwLock<RwiSession>> {
@@ -283,6 +292,10 @@ impl RwiGateway {
     }
 
     pub fn cache_event(&self, call_id: &CallId, event: &RwiEvent) -> u64 {
+        if let Some(capture) = &self.event_capture {
+            let _ = capture.send(event.clone());
+        }
+
         let mut cache_state = self
             .event_cache
             .lock()
@@ -360,6 +360,18 @@ fn build_command_result_event(
                     event["status"] = serde_json::json!("success");
                     event["data"] = serde_json::json!({ "track_id": track_id });
                 }
+                CommandResult::MediaInjectFormats {
+                    sample_rates,
+                    channels,
+                    selected,
+                } => {
+                    event["status"] = serde_json::json!("success");
+                    event["data"] = serde_json::json!({
+                        "sample_rates": sample_rates,
+                        "channels": channels,
+                        "selected": selected
+                    });
+                }
                 CommandResult::TransferAttended {
                     original_call_id,
                     consultation_call_id,
@@ -441,7 +453,7 @@ fn build_command_result_event(
 
 async fn handle_binary_message(
     data: &[u8],
-    _processor: Arc<RwiCommandProcessor>,
+    processor: Arc<RwiCommandProcessor>,
     session_id: &str,
     gateway: Arc<RwLock<RwiGateway>>,
 ) {
@@ -494,11 +506,23 @@ async fn handle_binary_message(
         return;
     }
 
-    tracing::debug!(
-        call_id = %call_id,
-        pcm_bytes = pcm_data.len(),
-        "PCM frame received"
-    );
+    if !processor
+        .check_inject_frame(&call_id, sample_rate as u32)
+        .await
+    {
+        return;
+    }
+
+    let pcm: Vec<i16> = pcm_data
+        .chunks_exact(2)
+        .map(|b| i16::from_le_bytes([b[0], b[1]]))
+        .collect();
+    if let Err(e) = processor
+        .inject_frame(&call_id, pcm, sample_rate as u32, is_last_frame)
+        .await
+    {
+        tracing::warn!(call_id = %call_id, error = %e, "Failed to inject PCM frame");
+    }
 }
 
 fn parse_action(
@@ -586,6 +610,7 @@ fn extract_call_id(cmd: &RwiCommandPayload) -> Option<String> {
         RwiCommandPayload::MediaStreamStop { call_id } => Some(call_id.clone()),
         RwiCommandPayload::MediaInjectStart(r) => Some(r.call_id.clone()),
         RwiCommandPayload::MediaInjectStop { call_id } => Some(call_id.clone()),
+        RwiCommandPayload::MediaInjectFormats { call_id } => Some(call_id.clone()),
         RwiCommandPayload::CallSendDtmf { call_id, .. } => Some(call_id.clone()),
         RwiCommandPayload::RecordStart(r) => Some(r.call_id.clone()),
         RwiCommandPayload::RecordPause { call_id } => Some(call_id.clone()),
@@ -4,6 +4,7 @@ pub mod gateway;
 pub mod handler;
 pub mod processor;
 pub mod proto;
+pub mod replay;
 pub mod session;
 pub mod transfer;
 
@@ -310,9 +310,19 @@ struct SupervisorState {
 #[allow(dead_code)]
 struct MediaStreamState;
 
+/// Sample rates accepted by `media.inject_start` for 16-bit PCM frames.
+pub const INJECT_SAMPLE_RATES: &[u32] = &[8000, 16000, 48000];
+/// Channel counts accepted by `media.inject_start`.
+pub const INJECT_CHANNELS: &[u32] = &[1];
+
+/// Format declared by `media.inject_start`; inbound PCM frames are checked
+/// against it.
 #[derive(Clone)]
-#[allow(dead_code)]
-struct MediaInjectState;
+struct MediaInjectState {
+    format: crate::rwi::session::MediaFormat,
+    /// PCM was sent since the last flush, so stopping must flush it.
+    injected: bool,
+}
 
 pub struct RwiCommandProcessor {
     call_registry: Arc<ActiveProxyCallRegistry>,
@@ -806,6 +816,9 @@ impl RwiCommandProcessor {
             RwiCommandPayload::MediaInjectStop { call_id } => {
                 return self.media_inject_stop(call_id).await;
             }
+            RwiCommandPayload::MediaInjectFormats { call_id } => {
+                return self.media_inject_formats(call_id).await;
+            }
             RwiCommandPayload::CallSendDtmf {
                 call_id,
                 leg_id,
@@ -966,6 +979,7 @@ impl RwiCommandProcessor {
             RwiCommandPayload::MediaStreamStop { call_id } => Some(call_id.clone()),
             RwiCommandPayload::MediaInjectStart(req) => Some(req.call_id.clone()),
             RwiCommandPayload::MediaInjectStop { call_id } => Some(call_id.clone()),
+            RwiCommandPayload::MediaInjectFormats { call_id } => Some(call_id.clone()),
             RwiCommandPayload::Originate(req) => Some(req.call_id.clone()),
             RwiCommandPayload::AttachCall { call_id, .. } => Some(call_id.clone()),
             RwiCommandPayload::DetachCall { call_id } => Some(call_id.clone()),
@@ -3805,11 +3819,29 @@ impl RwiCommandProcessor {
         &self,
         call_id: &str,
         _stream_id: &str,
-        _format: &crate::rwi::session::MediaFormat,
+        format: &crate::rwi::session::MediaFormat,
     ) -> Result<CommandResult, CommandError> {
         self.get_handle(call_id).await?;
+        if !INJECT_SAMPLE_RATES.contains(&format.sample_rate) {
+            return Err(CommandError::CommandFailed(format!(
+                "unsupported inject sample rate {}",
+                format.sample_rate
+            )));
+        }
+        if !INJECT_CHANNELS.contains(&format.channels) {
+            return Err(CommandError::CommandFailed(format!(
+                "unsupported inject channel count {}",
+                format.channels
+            )));
+        }
         let mut states = self.media_inject_states.write().await;
-        states.insert(call_id.to_string(), MediaInjectState);
+        states.insert(
+            call_id.to_string(),
+            MediaInjectState {
+                format: format.clone(),
+                injected: false,
+            },
+        );
         let event = RwiEvent::MediaStreamStarted {
             call_id: call_id.to_string(),
         };
@@ -3819,9 +3851,19 @@ impl RwiCommandProcessor {
     }
 
     async fn media_inject_stop(&self, call_id: &str) -> Result<CommandResult, CommandError> {
-        self.get_handle(call_id).await?;
+        let handle = self.get_handle(call_id).await?;
         let mut states = self.media_inject_states.write().await;
-        states.remove(call_id);
+        if states.remove(call_id).is_some_and(|state| state.injected) {
+            // Flush whatever is still buffered and return the output to the peer.
+            handle
+                .send_command(CallCommand::InjectPcm {
+                    leg_id: None,
+                    pcm: Vec::new(),
+                    sample_rate: INJECT_SAMPLE_RATES[0],
+                    flush: true,
+                })
+                .map_err(|e| CommandError::CommandFailed(e.to_string()))?;
+        }
         let event = RwiEvent::MediaStreamStopped {
             call_id: call_id.to_string(),
         };
@@ -3829,6 +3871,73 @@ impl RwiCommandProcessor {
         gw.send_event_to_call_owner(&call_id.to_string(), &event);
         Ok(CommandResult::Success)
     }
+
+    /// List the inject formats a call may select with `media.inject_start`,
+    /// along with the one currently selected, if any.
+    async fn media_inject_formats(&self, call_id: &str) -> Result<CommandResult, CommandError> {
+        self.get_handle(call_id).await?;
+        let selected = self
+            .media_inject_states
+            .read()
+            .await
+            .get(call_id)
+            .map(|state| state.format.clone());
+        Ok(CommandResult::MediaInjectFormats {
+            sample_rates: INJECT_SAMPLE_RATES.to_vec(),
+            channels: INJECT_CHANNELS.to_vec(),
+            selected,
+        })
+    }
+
+    /// Send an inbound PCM frame to the call's caller leg. The last frame of
+    /// a stream flushes the partial remainder and hands the output back to
+    /// the peer.
+    pub async fn inject_frame(
+        &self,
+        call_id: &str,
+        pcm: Vec<i16>,
+        sample_rate: u32,
+        is_last_frame: bool,
+    ) -> Result<(), CommandError> {
+        let handle = self.get_handle(call_id).await?;
+        handle
+            .send_command(CallCommand::InjectPcm {
+                leg_id: None,
+                pcm,
+                sample_rate,
+                flush: is_last_frame,
+            })
+            .map_err(|e| CommandError::CommandFailed(e.to_string()))?;
+        if let Some(state) = self.media_inject_states.write().await.get_mut(call_id) {
+            state.injected = !is_last_frame;
+        }
+        Ok(())
+    }
+
+    /// Check an inbound PCM frame against the format declared by
+    /// `media.inject_start`. Returns `false` (and logs) on a sample-rate
+    /// mismatch; frames for calls without a declared format are accepted.
+    pub async fn check_inject_frame(&self, call_id: &str, sample_rate: u32) -> bool {
+        if sample_rate == 0 {
+            warn!(call_id = %call_id, "Dropping PCM frame with zero sample rate");
+            crate::metrics::media::invalid_sample_rate("rwi_inject");
+            return false;
+        }
+        let states = self.media_inject_states.read().await;
+        let Some(state) = states.get(call_id) else {
+            return true;
+        };
+        if state.format.sample_rate != sample_rate {
+            warn!(
+                call_id = %call_id,
+                declared = state.format.sample_rate,
+                received = sample_rate,
+                "PCM frame sample rate does not match declared inject format"
+            );
+            return false;
+        }
+        true
+    }
 }
 
 #[derive(Debug)]
@@ -3844,6 +3953,11 @@ pub enum CommandResult {
     MediaPlay {
         track_id: String,
     },
+    MediaInjectFormats {
+        sample_rates: Vec<u32>,
+        channels: Vec<u32>,
+        selected: Option<crate::rwi::session::MediaFormat>,
+    },
     TransferAttended {
         original_call_id: String,
         consultation_call_id: String,
@@ -5577,6 +5691,167 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_media_inject_stop_flushes_only_injected_pcm() {
+        let registry = Arc::new(ActiveProxyCallRegistry::new());
+        let (_handle, mut rx) = create_test_call_with_rx(
+            &registry,
+            "call-inject",
+            "1001",
+            "2000",
+            DialDirection::Inbound,
+        );
+        let (processor, _cm) = create_test_processor_with_registry(registry);
+        let start = || {
+            RwiCommandPayload::MediaInjectStart(crate::rwi::session::MediaInjectRequest {
+                call_id: "call-inject".into(),
+                format: crate::rwi::session::MediaFormat {
+                    codec: "PCMU".into(),
+                    sample_rate: 8000,
+                    channels: 1,
+                    ptime_ms: Some(20),
+                },
+            })
+        };
+        let stop = || RwiCommandPayload::MediaInjectStop {
+            call_id: "call-inject".into(),
+        };
+
+        // Nothing injected: stopping must not touch the leg's output
+        processor.process_command(start()).await.unwrap();
+        processor.process_command(stop()).await.unwrap();
+        assert!(rx.try_recv().is_err());
+
+        processor.process_command(start()).await.unwrap();
+        processor
+            .inject_frame("call-inject", vec![0; 160], 8000, false)
+            .await
+            .unwrap();
+        assert!(rx.try_recv().is_ok());
+        processor.process_command(stop()).await.unwrap();
+        match rx.try_recv().expect("flush should be queued") {
+            CallCommand::InjectPcm { pcm, flush, .. } => {
+                assert!(pcm.is_empty());
+                assert!(flush);
+            }
+            other => panic!("unexpected command: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_media_inject_frame_checked_against_declared_format() {
+        let registry = Arc::new(ActiveProxyCallRegistry::new());
+        let _handle = create_test_call(&registry, "call-1", "1001", "2000", DialDirection::Inbound);
+        let (processor, _cm) = create_test_processor_with_registry(registry);
+
+        // No declared format: frames pass through unchecked.
+        assert!(processor.check_inject_frame("call-1", 16000).await);
+
+        processor
+            .process_command(RwiCommandPayload::MediaInjectStart(
+                crate::rwi::session::MediaInjectRequest {
+                    call_id: "call-1".into(),
+                    format: crate::rwi::session::MediaFormat {
+                        codec: "PCMU".into(),
+                        sample_rate: 8000,
+                        channels: 1,
+                        ptime_ms: Some(20),
+                    },
+                },
+            ))
+            .await
+            .unwrap();
+
+        assert!(processor.check_inject_frame("call-1", 8000).await);
+        assert!(!processor.check_inject_frame("call-1", 16000).await);
+    }
+
+    #[tokio::test]
+    async fn test_media_inject_frame_queues_inject_pcm() {
+        let registry = Arc::new(ActiveProxyCallRegistry::new());
+        let (_handle, mut rx) = create_test_call_with_rx(
+            &registry,
+            "call-inject",
+            "1001",
+            "2000",
+            DialDirection::Inbound,
+        );
+        let (processor, _cm) = create_test_processor_with_registry(registry);
+
+        processor
+            .inject_frame("call-inject", vec![1, 2, 3], 16000, true)
+            .await
+            .unwrap();
+
+        match rx.try_recv().expect("InjectPcm should be queued") {
+            CallCommand::InjectPcm {
+                leg_id,
+                pcm,
+                sample_rate,
+                flush,
+            } => {
+                assert!(leg_id.is_none());
+                assert_eq!(pcm, vec![1, 2, 3]);
+                assert_eq!(sample_rate, 16000);
+                assert!(flush);
+            }
+            other => panic!("unexpected command: {:?}", other),
+        }
+        assert!(
+            processor
+                .inject_frame("missing", vec![0; 160], 8000, false)
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_media_inject_formats_lists_and_selects() {
+        use crate::rwi::session::MediaFormat;
+
+        let registry = Arc::new(ActiveProxyCallRegistry::new());
+        let _handle = create_test_call(&registry, "call-1", "1001", "2000", DialDirection::Inbound);
+        let (processor, _cm) = create_test_processor_with_registry(registry);
+
+        async fn formats(processor: &RwiCommandProcessor) -> (Vec<u32>, Option<MediaFormat>) {
+            match processor
+                .process_command(RwiCommandPayload::MediaInjectFormats {
+                    call_id: "call-1".into(),
+                })
+                .await
+                .unwrap()
+            {
+                CommandResult::MediaInjectFormats {
+                    sample_rates,
+                    selected,
+                    ..
+                } => (sample_rates, selected),
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+        let (sample_rates, selected) = formats(&processor).await;
+        assert_eq!(sample_rates, INJECT_SAMPLE_RATES);
+        assert!(selected.is_none());
+
+        let inject = |sample_rate| {
+            RwiCommandPayload::MediaInjectStart(crate::rwi::session::MediaInjectRequest {
+                call_id: "call-1".into(),
+                format: MediaFormat {
+                    codec: "PCMU".into(),
+                    sample_rate,
+                    channels: 1,
+                    ptime_ms: Some(20),
+                },
+            })
+        };
+        assert!(processor.process_command(inject(11025)).await.is_err());
+        processor.process_command(inject(16000)).await.unwrap();
+
+        let (_, selected) = formats(&processor).await;
+        assert_eq!(selected.unwrap().sample_rate, 16000);
+        assert!(processor.check_inject_frame("call-1", 16000).await);
+    }
+
     #[tokio::test]
     async fn test_sip_message_no_server() {
         let (processor, _cm) = create_test_processor();
@@ -0,0 +1,197 @@
+//! Capture and replay of RWI event streams.
+//!
+//! [`RwiEventRecorder`] writes each event as a JSON line together with its
+//! offset from the start of the capture; [`replay_events`] re-emits a capture
+//! on an [`RwiEventTx`] in the original order, at the original pace or
+//! scaled by a speed factor. Intended for regression-testing client logic
+//! against a real call's event sequence.
+//!
+//! Live capture is opt-in via `rwi.event_capture_path`: the recorder is
+//! spawned on startup and attached to the gateway with
+//! [`crate::rwi::RwiGateway::set_event_capture`].
+
+use crate::rwi::proto::{RwiEvent, RwiEventTx};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+/// One line of a capture file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedEvent {
+    /// Milliseconds since the recorder was created.
+    pub offset_ms: u64,
+    pub event: RwiEvent,
+}
+
+pub struct RwiEventRecorder {
+    started: Instant,
+    file: tokio::fs::File,
+}
+
+impl RwiEventRecorder {
+    pub async fn create(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let file = tokio::fs::File::create(path)
+            .await
+            .with_context(|| format!("failed to create event capture {}", path.display()))?;
+        Ok(Self {
+            started: Instant::now(),
+            file,
+        })
+    }
+
+    pub async fn record(&mut self, event: &RwiEvent) -> Result<()> {
+        let entry = RecordedEvent {
+            offset_ms: self.started.elapsed().as_millis() as u64,
+            event: event.clone(),
+        };
+        let mut line = serde_json::to_vec(&entry)?;
+        line.push(b'\n');
+        self.file.write_all(&line).await?;
+        Ok(())
+    }
+
+    pub async fn finish(mut self) -> Result<()> {
+        self.file.flush().await?;
+        Ok(())
+    }
+
+    /// Record events from the returned sender on a background task until
+    /// every sender is dropped.
+    pub fn spawn(mut self) -> RwiEventTx {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            while let Some(event) = rx.recv().await {
+                if let Err(e) = self.record(&event).await {
+                    tracing::warn!("failed to record RWI event: {}", e);
+                }
+            }
+            if let Err(e) = self.finish().await {
+                tracing::warn!("failed to flush RWI event capture: {}", e);
+            }
+        });
+        tx
+    }
+}
+
+/// Replay a capture onto `tx`. `speed` scales the original gaps between
+/// events (2.0 replays twice as fast); `0.0` or less sends without delay.
+/// Returns the number of events sent.
+pub async fn replay_events(path: impl AsRef<Path>, tx: &RwiEventTx, speed: f64) -> Result<usize> {
+    let path = path.as_ref();
+    let file = tokio::fs::File::open(path)
+        .await
+        .with_context(|| format!("failed to open event capture {}", path.display()))?;
+    let mut lines = BufReader::new(file).lines();
+    let mut last_offset_ms = 0u64;
+    let mut sent = 0;
+
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry: RecordedEvent = serde_json::from_str(&line)
+            .with_context(|| format!("invalid event capture line {}", sent + 1))?;
+        if speed > 0.0 {
+            let gap_ms = entry.offset_ms.saturating_sub(last_offset_ms);
+            if gap_ms > 0 {
+                tokio::time::sleep(Duration::from_secs_f64(gap_ms as f64 / 1000.0 / speed)).await;
+            }
+        }
+        last_offset_ms = entry.offset_ms;
+        tx.send(entry.event)
+            .map_err(|_| anyhow::anyhow!("event receiver dropped during replay"))?;
+        sent += 1;
+    }
+    Ok(sent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_record_and_replay_preserves_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("call.events.jsonl");
+
+        let events = vec![
+            RwiEvent::CallRinging {
+                call_id: "c1".into(),
+            },
+            RwiEvent::CallAnswered {
+                call_id: "c1".into(),
+            },
+            RwiEvent::CallHangup {
+                call_id: "c1".into(),
+                reason: Some("normal".into()),
+                sip_status: Some(200),
+            },
+        ];
+
+        let mut recorder = RwiEventRecorder::create(&path).await.unwrap();
+        for event in &events {
+            recorder.record(event).await.unwrap();
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        recorder.finish().await.unwrap();
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let sent = replay_events(&path, &tx, 10.0).await.unwrap();
+        assert_eq!(sent, events.len());
+
+        for expected in &events {
+            let got = rx.try_recv().expect("replayed event");
+            assert_eq!(
+                serde_json::to_value(&got).unwrap(),
+                serde_json::to_value(expected).unwrap()
+            );
+        }
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_gateway_capture_records_dispatched_events() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("live.events.jsonl");
+
+        let mut gateway = crate::rwi::RwiGateway::new();
+        let recorder = RwiEventRecorder::create(&path).await.unwrap();
+        gateway.set_event_capture(recorder.spawn());
+
+        let call_id = "c1".to_string();
+        gateway.fan_out_event_to_context(
+            "default",
+            &RwiEvent::CallRinging {
+                call_id: call_id.clone(),
+            },
+            &call_id,
+        );
+        gateway.send_event_to_call_owner(
+            &call_id,
+            &RwiEvent::CallAnswered {
+                call_id: call_id.clone(),
+            },
+        );
+        // Dropping the gateway closes the capture and flushes the file
+        drop(gateway);
+
+        let mut captured = String::new();
+        for _ in 0..100 {
+            captured = tokio::fs::read_to_string(&path).await.unwrap();
+            if captured.lines().count() == 2 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        let events: Vec<RecordedEvent> = captured
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0].event, RwiEvent::CallRinging { .. }));
+        assert!(matches!(events[1].event, RwiEvent::CallAnswered { .. }));
+    }
+}
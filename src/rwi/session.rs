@@ -129,6 +129,9 @@ pub enum RwiCommandPayload {
     MediaInjectStop {
         call_id: String,
     },
+    MediaInjectFormats {
+        call_id: String,
+    },
     CallSendDtmf {
         call_id: String,
         leg_id: Option<String>,
@@ -371,7 +374,7 @@ fn default_direction() -> String {
     "sendrecv".to_string()
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MediaFormat {
     #[serde(default = "default_codec")]
     pub codec: String,
@@ -622,6 +625,8 @@ pub enum RwiRequestPayload {
     MediaInjectStart(MediaInjectRequest),
     #[serde(rename = "media.inject_stop")]
     MediaInjectStop { call_id: Option<String> },
+    #[serde(rename = "media.inject_formats")]
+    MediaInjectFormats { call_id: Option<String> },
     #[serde(rename = "call.send_dtmf")]
     CallSendDtmf {
         call_id: Option<String>,
@@ -887,6 +892,11 @@ impl From<RwiRequest> for RwiCommandPayload {
             RwiRequestPayload::MediaInjectStop { call_id } => RwiCommandPayload::MediaInjectStop {
                 call_id: call_id.unwrap_or_default(),
             },
+            RwiRequestPayload::MediaInjectFormats { call_id } => {
+                RwiCommandPayload::MediaInjectFormats {
+                    call_id: call_id.unwrap_or_default(),
+                }
+            }
             RwiRequestPayload::CallSendDtmf {
                 call_id,
                 leg_id,
@@ -0,0 +1,133 @@
+//! Shared caches for synthesized prompts.
+//!
+//! Every service keeps its own files under `cache_dir`; a [`TtsCacheStore`]
+//! sits behind that local cache so several gateways can reuse audio one of
+//! them already paid to synthesize.
+//!
+//! Only object storage (a shared directory or an S3-compatible bucket) is
+//! supported as a shared backend; there is no Redis store.
+
+use crate::storage::{Storage, StorageConfig};
+use anyhow::Result;
+use async_trait::async_trait;
+use bytes::Bytes;
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait TtsCacheStore: Send + Sync {
+    /// Audio stored under `key`, or `None` on a miss.
+    async fn get(&self, key: &str) -> Result<Option<Bytes>>;
+    async fn put(&self, key: &str, audio: Bytes) -> Result<()>;
+}
+
+/// Cache kept in object storage (a shared directory or an S3-compatible
+/// bucket). Expiry is left to the bucket's lifecycle rules.
+pub struct StorageCacheStore {
+    storage: Storage,
+}
+
+/// Stores keyed by their serialized storage config, so per-call services
+/// reuse one object store client instead of building one per call.
+static STORAGE_STORES: Lazy<DashMap<String, Arc<StorageCacheStore>>> = Lazy::new(DashMap::new);
+
+impl StorageCacheStore {
+    pub fn new(config: &StorageConfig) -> Result<Self> {
+        Ok(Self {
+            storage: Storage::new(config)?,
+        })
+    }
+
+    /// Store for `config`, built on first use and shared afterwards.
+    pub fn shared(config: &StorageConfig) -> Result<Arc<Self>> {
+        let key = serde_json::to_string(config)?;
+        if let Some(store) = STORAGE_STORES.get(&key) {
+            return Ok(store.clone());
+        }
+        let store = Arc::new(Self::new(config)?);
+        Ok(STORAGE_STORES.entry(key).or_insert(store).clone())
+    }
+}
+
+#[async_trait]
+impl TtsCacheStore for StorageCacheStore {
+    async fn get(&self, key: &str) -> Result<Option<Bytes>> {
+        match self.storage.read(key).await {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(e)
+                if matches!(
+                    e.downcast_ref::<object_store::Error>(),
+                    Some(object_store::Error::NotFound { .. })
+                ) =>
+            {
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn put(&self, key: &str, audio: Bytes) -> Result<()> {
+        self.storage.write(key, audio).await
+    }
+}
+
+/// Process-local cache, useful for tests and single-node setups.
+#[derive(Default)]
+pub struct MemoryCacheStore {
+    entries: DashMap<String, Bytes>,
+}
+
+impl MemoryCacheStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[async_trait]
+impl TtsCacheStore for MemoryCacheStore {
+    async fn get(&self, key: &str) -> Result<Option<Bytes>> {
+        Ok(self.entries.get(key).map(|entry| entry.clone()))
+    }
+
+    async fn put(&self, key: &str, audio: Bytes) -> Result<()> {
+        self.entries.insert(key.to_string(), audio);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_storage_cache_store_miss_and_hit() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = StorageConfig::Local {
+            path: dir.path().to_string_lossy().to_string(),
+        };
+        let store = StorageCacheStore::shared(&config).unwrap();
+        assert!(Arc::ptr_eq(
+            &store,
+            &StorageCacheStore::shared(&config).unwrap()
+        ));
+
+        assert!(store.get("missing.wav").await.unwrap().is_none());
+        store
+            .put("prompt.wav", Bytes::from_static(b"RIFF"))
+            .await
+            .unwrap();
+        assert_eq!(
+            store.get("prompt.wav").await.unwrap(),
+            Some(Bytes::from_static(b"RIFF"))
+        );
+    }
+}
@@ -1,5 +1,6 @@
-use super::CliTtsConfig;
+use super::{CliTtsConfig, TtsTimeout};
 use anyhow::{Result, anyhow};
+use std::time::Duration;
 
 pub async fn synthesize_cli(
     cfg: &CliTtsConfig,
@@ -17,11 +18,17 @@ pub async fn synthesize_cli(
         })
         .collect();
 
-    let output = tokio::process::Command::new(&cfg.command)
-        .args(&args)
-        .output()
-        .await
-        .map_err(|e| anyhow!("Failed to execute TTS CLI {}: {}", cfg.command, e))?;
+    let timeout = Duration::from_secs(cfg.timeout_seconds);
+    let output = tokio::time::timeout(
+        timeout,
+        tokio::process::Command::new(&cfg.command)
+            .args(&args)
+            .kill_on_drop(true)
+            .output(),
+    )
+    .await
+    .map_err(|_| TtsTimeout(timeout))?
+    .map_err(|e| anyhow!("Failed to execute TTS CLI {}: {}", cfg.command, e))?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -44,10 +51,28 @@ mod tests {
             command: "echo".to_string(),
             args: vec!["hello {text}".to_string()],
             output_format: "wav".to_string(),
+            timeout_seconds: 5,
         };
 
         // echo doesn't write to file, so this just tests arg replacement and success path
         let result = synthesize_cli(&cfg, "world", "en-US", &path).await;
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_cli_tts_timeout() {
+        let cfg = CliTtsConfig {
+            command: "sleep".to_string(),
+            args: vec!["5".to_string()],
+            output_format: "wav".to_string(),
+            timeout_seconds: 1,
+        };
+
+        let started = std::time::Instant::now();
+        let err = synthesize_cli(&cfg, "world", "en-US", "/dev/null")
+            .await
+            .unwrap_err();
+        assert!(err.downcast_ref::<TtsTimeout>().is_some());
+        assert!(started.elapsed() < Duration::from_secs(3));
+    }
 }
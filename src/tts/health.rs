@@ -0,0 +1,170 @@
+//! Reachability checks for configured TTS providers.
+//!
+//! Probes are deliberately cheap: an HTTP `HEAD` against the provider URL or
+//! a lookup of the CLI binary. Nothing is synthesized, so checks cost no
+//! provider quota.
+
+use super::{SHARED_HTTP_CLIENT, TtsDriverConfig, TtsTimeout};
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+/// Outcome of the latest probe of one provider.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProviderHealth {
+    pub healthy: bool,
+    pub latency_ms: u64,
+    pub checked_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Latest results keyed by provider endpoint.
+static PROVIDER_HEALTH: Lazy<DashMap<String, ProviderHealth>> = Lazy::new(DashMap::new);
+
+/// Snapshot of the latest result for every checked provider.
+pub fn provider_health() -> BTreeMap<String, ProviderHealth> {
+    PROVIDER_HEALTH
+        .iter()
+        .map(|entry| (entry.key().clone(), entry.value().clone()))
+        .collect()
+}
+
+/// Probe a provider once. HTTP providers count as reachable on any response
+/// below 500, since many reject `HEAD` or an empty request with a 4xx.
+pub async fn probe(driver: &TtsDriverConfig, client: &reqwest::Client) -> Result<()> {
+    match driver {
+        TtsDriverConfig::Http(cfg) => {
+            let mut req = client.head(&cfg.url);
+            for (key, value) in &cfg.headers {
+                req = req.header(key, value);
+            }
+            let timeout = Duration::from_secs(cfg.timeout_seconds);
+            let resp = tokio::time::timeout(timeout, req.send())
+                .await
+                .map_err(|_| TtsTimeout(timeout))?
+                .map_err(|e| anyhow!("TTS provider unreachable: {}", e))?;
+            if resp.status().is_server_error() {
+                return Err(anyhow!("TTS provider returned {}", resp.status()));
+            }
+            Ok(())
+        }
+        TtsDriverConfig::Cli(cfg) => {
+            if command_exists(&cfg.command) {
+                Ok(())
+            } else {
+                Err(anyhow!("TTS command not found: {}", cfg.command))
+            }
+        }
+    }
+}
+
+fn command_exists(command: &str) -> bool {
+    if command.contains(std::path::MAIN_SEPARATOR) {
+        return Path::new(command).is_file();
+    }
+    std::env::var_os("PATH")
+        .map(|paths| std::env::split_paths(&paths).any(|dir| dir.join(command).is_file()))
+        .unwrap_or(false)
+}
+
+/// Probe every driver and record the results.
+pub async fn check_providers(drivers: &[TtsDriverConfig], client: &reqwest::Client) {
+    for driver in drivers {
+        let started = Instant::now();
+        let result = probe(driver, client).await;
+        let provider = driver.provider();
+        if let Err(e) = &result {
+            warn!(provider = %provider, error = %e, "TTS provider health check failed");
+        }
+        crate::metrics::tts::set_provider_status(provider, result.is_ok());
+        PROVIDER_HEALTH.insert(
+            provider.to_string(),
+            ProviderHealth {
+                healthy: result.is_ok(),
+                latency_ms: started.elapsed().as_millis() as u64,
+                checked_at: Utc::now(),
+                error: result.err().map(|e| e.to_string()),
+            },
+        );
+    }
+}
+
+/// Probe `drivers` every `interval` until `token` is cancelled.
+pub fn spawn_health_checks(
+    drivers: Vec<TtsDriverConfig>,
+    interval: Duration,
+    token: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            check_providers(&drivers, &SHARED_HTTP_CLIENT).await;
+            tokio::select! {
+                _ = token.cancelled() => break,
+                _ = tokio::time::sleep(interval) => {}
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tts::{BodyFormat, CliTtsConfig, HttpTtsConfig};
+    use axum::{Router, http::StatusCode, routing::get};
+    use std::collections::HashMap;
+
+    fn http_driver(url: String) -> TtsDriverConfig {
+        TtsDriverConfig::Http(HttpTtsConfig {
+            url,
+            method: "GET".to_string(),
+            param_name: "text".to_string(),
+            extra_params: HashMap::new(),
+            headers: HashMap::new(),
+            output_format: "wav".to_string(),
+            timeout_seconds: 5,
+            body_format: BodyFormat::Query,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_provider_health_reports_healthy_and_unhealthy() {
+        let app = Router::new()
+            .route("/tts", get(|| async { StatusCode::BAD_REQUEST }))
+            .route("/down", get(|| async { StatusCode::SERVICE_UNAVAILABLE }));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.ok();
+        });
+
+        let healthy = format!("http://127.0.0.1:{}/tts", port);
+        let unhealthy = format!("http://127.0.0.1:{}/down", port);
+        let missing = "rustpbx-no-such-tts-binary".to_string();
+        let drivers = vec![
+            http_driver(healthy.clone()),
+            http_driver(unhealthy.clone()),
+            TtsDriverConfig::Cli(CliTtsConfig {
+                command: missing.clone(),
+                args: Vec::new(),
+                output_format: "wav".to_string(),
+                timeout_seconds: 5,
+            }),
+        ];
+        check_providers(&drivers, &reqwest::Client::new()).await;
+
+        let report = provider_health();
+        assert!(report[&healthy].healthy);
+        assert!(report[&healthy].error.is_none());
+        assert!(!report[&unhealthy].healthy);
+        assert!(report[&unhealthy].error.as_ref().unwrap().contains("503"));
+        assert!(!report[&missing].healthy);
+    }
+}
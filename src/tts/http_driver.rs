@@ -1,4 +1,4 @@
-use super::{BodyFormat, HttpTtsConfig};
+use super::{BodyFormat, HttpTtsConfig, TtsTimeout};
 use anyhow::{Result, anyhow};
 use std::time::Duration;
 
@@ -48,16 +48,21 @@ pub async fn synthesize_http(
         req = req.header(key, value);
     }
 
-    let resp = tokio::time::timeout(Duration::from_secs(cfg.timeout_seconds), req.send())
-        .await
-        .map_err(|_| anyhow!("TTS HTTP request timed out after {}s", cfg.timeout_seconds))?
-        .map_err(|e| anyhow!("TTS HTTP request failed: {}", e))?;
+    let timeout = Duration::from_secs(cfg.timeout_seconds);
+    tokio::time::timeout(timeout, async move {
+        let resp = req
+            .send()
+            .await
+            .map_err(|e| anyhow!("TTS HTTP request failed: {}", e))?;
 
-    if !resp.status().is_success() {
-        return Err(anyhow!("TTS HTTP returned {}", resp.status()));
-    }
+        if !resp.status().is_success() {
+            return Err(anyhow!("TTS HTTP returned {}", resp.status()));
+        }
 
-    resp.bytes()
-        .await
-        .map_err(|e| anyhow!("Failed to read TTS response body: {}", e))
+        resp.bytes()
+            .await
+            .map_err(|e| anyhow!("Failed to read TTS response body: {}", e))
+    })
+    .await
+    .map_err(|_| TtsTimeout(timeout))?
 }
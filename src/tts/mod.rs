@@ -1,16 +1,27 @@
 //! Text-to-Speech (TTS) service for dynamic audio generation in IVR.
 //!
-//! Supports HTTP and CLI drivers, with local file caching.
+//! Supports HTTP and CLI drivers, with local file caching and an optional
+//! shared cache behind it.
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::Path;
-use tracing::debug;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::{debug, warn};
 
+pub mod cache;
 pub mod cli_driver;
+pub mod health;
 pub mod http_driver;
 
+use crate::storage::StorageConfig;
+use cache::{StorageCacheStore, TtsCacheStore};
 use cli_driver::synthesize_cli;
 use http_driver::synthesize_http;
 
@@ -31,7 +42,34 @@ pub struct TtsConfig {
     pub cache_dir: String,
     #[serde(default = "default_tts_cache_ttl_seconds")]
     pub cache_ttl_seconds: u64,
+    /// Maximum in-flight synthesis requests per provider endpoint, shared by
+    /// every service targeting it with the same limit. Services configured
+    /// with a different limit (another IVR, or after a reload) get their own
+    /// pool. Unlimited when unset. Only TTS requests are limited; ASR
+    /// providers are not covered.
+    #[serde(default)]
+    pub max_concurrency: Option<usize>,
+    /// How long to wait for a free slot once `max_concurrency` is reached.
+    /// `0` fails immediately; unset waits indefinitely.
+    #[serde(default)]
+    pub acquire_timeout_ms: Option<u64>,
+    /// Characters one service (i.e. one call) may send to providers before
+    /// further requests are rejected. Cache hits don't count. Unlimited when unset.
+    #[serde(default)]
+    pub max_characters_per_call: Option<usize>,
+    /// Storage shared by several gateways, consulted when the local cache
+    /// misses and filled after each synthesis. Object storage only; Redis is
+    /// not supported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shared_cache: Option<StorageConfig>,
+    /// Open a connection to the primary provider when a call starts so the
+    /// first prompt doesn't pay for TCP and TLS setup.
+    #[serde(default)]
+    pub warmup: bool,
     pub driver: TtsDriverConfig,
+    /// Drivers tried in order when `driver` fails.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fallback: Vec<TtsDriverConfig>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -41,6 +79,23 @@ pub enum TtsDriverConfig {
     Cli(CliTtsConfig),
 }
 
+impl TtsDriverConfig {
+    /// Endpoint identifying the provider, used for logging and limits.
+    pub fn provider(&self) -> &str {
+        match self {
+            TtsDriverConfig::Http(cfg) => &cfg.url,
+            TtsDriverConfig::Cli(cfg) => &cfg.command,
+        }
+    }
+
+    pub fn output_format(&self) -> &str {
+        match self {
+            TtsDriverConfig::Http(cfg) => &cfg.output_format,
+            TtsDriverConfig::Cli(cfg) => &cfg.output_format,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct HttpTtsConfig {
     pub url: String,
@@ -67,6 +122,8 @@ pub struct CliTtsConfig {
     pub args: Vec<String>,
     #[serde(default = "default_output_format")]
     pub output_format: String,
+    #[serde(default = "default_timeout_seconds")]
+    pub timeout_seconds: u64,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -97,76 +154,311 @@ fn default_body_format() -> BodyFormat {
     BodyFormat::Query
 }
 
+/// Error returned when a whole synthesis request (connect, response and
+/// body, or CLI run) exceeds the driver's `timeout_seconds`.
+#[derive(Debug, thiserror::Error)]
+#[error("TTS synthesis timed out after {0:?}")]
+pub struct TtsTimeout(pub Duration);
+
+/// Error returned when a request would exceed `max_characters_per_call`.
+#[derive(Debug, thiserror::Error)]
+#[error("TTS character budget exceeded: {used} used + {requested} requested > {limit}")]
+pub struct TtsBudgetExceeded {
+    pub used: usize,
+    pub requested: usize,
+    pub limit: usize,
+}
+
+/// Concurrency limiters keyed by provider endpoint and limit, so that
+/// per-call services targeting the same provider share one budget.
+#[derive(Default)]
+pub struct ProviderLimits(DashMap<(String, usize), Arc<Semaphore>>);
+
+impl ProviderLimits {
+    fn semaphore(&self, provider: &str, limit: usize) -> Arc<Semaphore> {
+        self.0
+            .entry((provider.to_string(), limit))
+            .or_insert_with(|| Arc::new(Semaphore::new(limit)))
+            .clone()
+    }
+}
+
+/// Limiters shared by every service in the process.
+static PROVIDER_LIMITS: Lazy<Arc<ProviderLimits>> = Lazy::new(Default::default);
+
+/// HTTP client shared by every service so per-call services reuse pooled
+/// keep-alive connections and TLS sessions to the provider.
+static SHARED_HTTP_CLIENT: Lazy<reqwest::Client> = Lazy::new(reqwest::Client::new);
+
 /// Shared TTS service that synthesizes text into cached audio files.
 pub struct TtsService {
     config: TtsConfig,
     client: reqwest::Client,
+    /// Characters sent to providers so far, checked against the call budget.
+    characters_used: AtomicUsize,
+    shared_cache: Option<Arc<dyn TtsCacheStore>>,
+    provider_limits: Arc<ProviderLimits>,
 }
 
 impl TtsService {
     pub fn new(config: TtsConfig) -> Self {
+        Self::with_client(config, SHARED_HTTP_CLIENT.clone())
+    }
+
+    pub fn with_client(config: TtsConfig, client: reqwest::Client) -> Self {
+        let shared_cache = config.shared_cache.as_ref().and_then(|storage| {
+            match StorageCacheStore::shared(storage) {
+                Ok(store) => Some(store as Arc<dyn TtsCacheStore>),
+                Err(e) => {
+                    warn!(error = %e, "TTS shared cache unavailable, using local cache only");
+                    None
+                }
+            }
+        });
         Self {
             config,
-            client: reqwest::Client::new(),
+            client,
+            characters_used: AtomicUsize::new(0),
+            shared_cache,
+            provider_limits: PROVIDER_LIMITS.clone(),
         }
     }
 
-    pub fn with_client(config: TtsConfig, client: reqwest::Client) -> Self {
-        Self { config, client }
+    /// Replace the process-wide provider limiters.
+    pub fn with_provider_limits(mut self, limits: Arc<ProviderLimits>) -> Self {
+        self.provider_limits = limits;
+        self
+    }
+
+    /// Replace the shared cache built from `shared_cache` config.
+    pub fn with_cache_store(mut self, store: Arc<dyn TtsCacheStore>) -> Self {
+        self.shared_cache = Some(store);
+        self
+    }
+
+    /// Prime the pooled connection to the primary provider with a cheap
+    /// `HEAD` request. Does nothing unless `warmup` is enabled or for CLI
+    /// drivers; failures are logged and left to the first real request.
+    pub async fn warmup(&self) {
+        if !self.config.warmup || !matches!(self.config.driver, TtsDriverConfig::Http(_)) {
+            return;
+        }
+        let provider = self.config.driver.provider();
+        match health::probe(&self.config.driver, &self.client).await {
+            Ok(()) => debug!(provider = %provider, "TTS connection warmed up"),
+            Err(e) => warn!(provider = %provider, error = %e, "TTS warmup failed"),
+        }
+    }
+
+    /// Characters synthesized by providers so far.
+    pub fn characters_used(&self) -> usize {
+        self.characters_used.load(Ordering::Relaxed)
     }
 
     /// Synthesize `text` into a local audio file path.
-    /// Returns the cached path if it already exists and is fresh.
+    /// Returns the cached path if it already exists and is fresh. When the
+    /// primary driver fails, each `fallback` driver is tried in order.
     pub async fn synthesize(&self, text: &str, voice: Option<&str>) -> Result<String> {
-        let cache_key = self.cache_key(text, voice);
-        let cache_path = self.cache_path(&cache_key);
+        let drivers: Vec<&TtsDriverConfig> = std::iter::once(&self.config.driver)
+            .chain(self.config.fallback.iter())
+            .collect();
+        let mut last_error = None;
+        for (index, driver) in drivers.iter().enumerate() {
+            match self.synthesize_with(driver, text, voice).await {
+                Ok(path) => return Ok(path),
+                Err(e) if e.is::<TtsBudgetExceeded>() => return Err(e),
+                Err(e) => {
+                    if index + 1 < drivers.len() {
+                        warn!(provider = %driver.provider(), error = %e, "TTS provider failed, trying fallback");
+                    }
+                    last_error = Some(e);
+                }
+            }
+        }
+        Err(last_error.unwrap_or_else(|| anyhow!("no TTS driver configured")))
+    }
+
+    async fn synthesize_with(
+        &self,
+        driver: &TtsDriverConfig,
+        text: &str,
+        voice: Option<&str>,
+    ) -> Result<String> {
+        let cache_key = self.cache_key(driver, text, voice);
+        let cache_path = self.cache_path(driver, &cache_key);
 
         if self.is_cache_valid(&cache_path).await {
             debug!(cache_path = %cache_path, "TTS cache hit");
             return Ok(cache_path);
         }
 
-        debug!(text = %text, voice = ?voice, "TTS synthesizing");
+        let shared_key = format!("{}.{}", cache_key, driver.output_format());
+        if let Some(store) = &self.shared_cache {
+            match store.get(&shared_key).await {
+                Ok(Some(audio)) => {
+                    tokio::fs::create_dir_all(
+                        Path::new(&cache_path).parent().unwrap_or(Path::new(".")),
+                    )
+                    .await?;
+                    tokio::fs::write(&cache_path, audio).await?;
+                    debug!(cache_path = %cache_path, "TTS shared cache hit");
+                    return Ok(cache_path);
+                }
+                Ok(None) => {}
+                Err(e) => warn!(error = %e, "TTS shared cache lookup failed"),
+            }
+        }
+
+        let requested = text.chars().count();
+        self.reserve_budget(requested)?;
+        let result = self
+            .synthesize_uncached(driver, text, voice, &cache_path, &shared_key)
+            .await;
+        if result.is_err() {
+            self.characters_used.fetch_sub(requested, Ordering::Relaxed);
+        }
+        result
+    }
 
-        match &self.config.driver {
+    async fn synthesize_uncached(
+        &self,
+        driver: &TtsDriverConfig,
+        text: &str,
+        voice: Option<&str>,
+        cache_path: &str,
+        shared_key: &str,
+    ) -> Result<String> {
+        let _permit = self.acquire_provider_permit(driver.provider()).await?;
+        debug!(text = %text, voice = ?voice, provider = %driver.provider(), "TTS synthesizing");
+
+        match driver {
             TtsDriverConfig::Http(cfg) => {
                 let bytes = synthesize_http(cfg, &self.client, text, voice).await?;
-                tokio::fs::create_dir_all(
-                    Path::new(&cache_path).parent().unwrap_or(Path::new(".")),
-                )
-                .await?;
-                tokio::fs::write(&cache_path, bytes).await?;
-                Ok(cache_path)
+                tokio::fs::create_dir_all(Path::new(cache_path).parent().unwrap_or(Path::new(".")))
+                    .await?;
+                tokio::fs::write(cache_path, &bytes).await?;
+                self.share(shared_key, bytes).await;
+                Ok(cache_path.to_string())
             }
             TtsDriverConfig::Cli(cfg) => {
-                tokio::fs::create_dir_all(
-                    Path::new(&cache_path).parent().unwrap_or(Path::new(".")),
-                )
-                .await?;
+                tokio::fs::create_dir_all(Path::new(cache_path).parent().unwrap_or(Path::new(".")))
+                    .await?;
                 let voice_str = voice.unwrap_or("");
-                synthesize_cli(cfg, text, voice_str, &cache_path).await?;
-                Ok(cache_path)
+                synthesize_cli(cfg, text, voice_str, cache_path).await?;
+                if self.shared_cache.is_some() {
+                    match tokio::fs::read(cache_path).await {
+                        Ok(audio) => self.share(shared_key, audio.into()).await,
+                        Err(e) => warn!(error = %e, "failed to read TTS output for shared cache"),
+                    }
+                }
+                Ok(cache_path.to_string())
+            }
+        }
+    }
+
+    /// Publish freshly synthesized audio to the shared cache. Failures only
+    /// cost other gateways a re-synthesis, so they are logged and ignored.
+    async fn share(&self, key: &str, audio: bytes::Bytes) {
+        if let Some(store) = &self.shared_cache
+            && let Err(e) = store.put(key, audio).await
+        {
+            warn!(error = %e, "failed to store TTS audio in shared cache");
+        }
+    }
+
+    /// Add `requested` to the characters used, failing without changing
+    /// anything if that would exceed the call budget. Reserving up front
+    /// keeps concurrent prompts in one call from overrunning the budget; the
+    /// caller refunds the reservation if synthesis fails.
+    fn reserve_budget(&self, requested: usize) -> Result<(), TtsBudgetExceeded> {
+        let Some(limit) = self.config.max_characters_per_call else {
+            self.characters_used.fetch_add(requested, Ordering::Relaxed);
+            return Ok(());
+        };
+        let mut used = self.characters_used();
+        loop {
+            if used + requested > limit {
+                return Err(TtsBudgetExceeded {
+                    used,
+                    requested,
+                    limit,
+                });
+            }
+            match self.characters_used.compare_exchange_weak(
+                used,
+                used + requested,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return Ok(()),
+                Err(actual) => used = actual,
             }
         }
     }
 
-    fn cache_key(&self, text: &str, voice: Option<&str>) -> String {
-        use std::hash::{DefaultHasher, Hash, Hasher};
-        let mut hasher = DefaultHasher::new();
-        text.hash(&mut hasher);
-        voice.hash(&mut hasher);
-        let driver_hash = format!("{:?}", self.config.driver);
-        driver_hash.hash(&mut hasher);
-        format!("{:x}", hasher.finish())
+    async fn acquire_provider_permit(
+        &self,
+        provider: &str,
+    ) -> Result<Option<OwnedSemaphorePermit>> {
+        let Some(limit) = self.config.max_concurrency.filter(|limit| *limit > 0) else {
+            return Ok(None);
+        };
+        let semaphore = self.provider_limits.semaphore(provider, limit);
+        let permit = match self.config.acquire_timeout_ms {
+            None => semaphore.acquire_owned().await?,
+            Some(0) => semaphore.try_acquire_owned().map_err(|_| {
+                anyhow!(
+                    "TTS provider {} is at its concurrency limit of {}",
+                    provider,
+                    limit
+                )
+            })?,
+            Some(wait_ms) => {
+                tokio::time::timeout(Duration::from_millis(wait_ms), semaphore.acquire_owned())
+                    .await
+                    .map_err(|_| {
+                        anyhow!(
+                            "TTS provider {} still at its concurrency limit of {} after {}ms",
+                            provider,
+                            limit,
+                            wait_ms
+                        )
+                    })??
+            }
+        };
+        Ok(Some(permit))
     }
 
-    fn cache_path(&self, cache_key: &str) -> String {
-        let ext = match self.config.driver {
-            TtsDriverConfig::Http(ref c) => c.output_format.clone(),
-            TtsDriverConfig::Cli(ref c) => c.output_format.clone(),
+    /// Digest of the prompt and driver settings. It must be identical across
+    /// processes and builds, since gateways share cached prompts by this key.
+    fn cache_key(&self, driver: &TtsDriverConfig, text: &str, voice: Option<&str>) -> String {
+        use sha2::{Digest, Sha256};
+        use std::collections::BTreeMap;
+        let driver = match driver {
+            TtsDriverConfig::Http(cfg) => serde_json::json!({
+                "http": {
+                    "url": cfg.url,
+                    "method": cfg.method,
+                    "param_name": cfg.param_name,
+                    "extra_params": cfg.extra_params.iter().collect::<BTreeMap<_, _>>(),
+                    "headers": cfg.headers.iter().collect::<BTreeMap<_, _>>(),
+                    "output_format": cfg.output_format,
+                    "timeout_seconds": cfg.timeout_seconds,
+                    "body_format": cfg.body_format,
+                }
+            }),
+            TtsDriverConfig::Cli(cfg) => serde_json::json!({ "cli": cfg }),
         };
+        let canonical = serde_json::json!([text, voice, driver]).to_string();
+        Sha256::digest(canonical.as_bytes())
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+
+    fn cache_path(&self, driver: &TtsDriverConfig, cache_key: &str) -> String {
         Path::new(&self.config.cache_dir)
-            .join(format!("{}.{}", cache_key, ext))
+            .join(format!("{}.{}", cache_key, driver.output_format()))
             .to_string_lossy()
             .to_string()
     }
@@ -182,7 +474,7 @@ impl TtsService {
         match tokio::fs::metadata(path).await {
             Ok(meta) => match meta.modified() {
                 Ok(modified) => {
-                    let ttl = std::time::Duration::from_secs(self.config.cache_ttl_seconds);
+                    let ttl = Duration::from_secs(self.config.cache_ttl_seconds);
                     std::time::SystemTime::now()
                         .duration_since(modified)
                         .map(|age| age < ttl)
@@ -219,12 +511,47 @@ mod tests {
         std::fs::read(tmp.path()).unwrap()
     }
 
+    /// Service with its own provider limiters, untouched by other tests.
+    fn test_service(config: TtsConfig) -> TtsService {
+        TtsService::new(config).with_provider_limits(Default::default())
+    }
+
+    /// Serve `app` on an ephemeral local port and return the port.
+    async fn serve(app: Router) -> u16 {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.ok();
+        });
+        port
+    }
+
+    /// A provider answering `/tts` with a WAV after `delay`.
+    fn slow_wav_router(delay: Duration) -> Router {
+        let wav = make_wav_bytes();
+        Router::new().route(
+            "/tts",
+            get(move || {
+                let wav = wav.clone();
+                async move {
+                    tokio::time::sleep(delay).await;
+                    wav
+                }
+            }),
+        )
+    }
+
     #[tokio::test]
     async fn test_tts_cache_hit() {
         let cache_dir = tempfile::tempdir().unwrap();
         let config = TtsConfig {
             cache_dir: cache_dir.path().to_string_lossy().to_string(),
             cache_ttl_seconds: 3600,
+            max_concurrency: None,
+            acquire_timeout_ms: None,
+            max_characters_per_call: None,
+            shared_cache: None,
+            warmup: false,
             driver: TtsDriverConfig::Http(HttpTtsConfig {
                 url: "http://localhost:9999/tts".to_string(),
                 method: "GET".to_string(),
@@ -235,10 +562,12 @@ mod tests {
                 timeout_seconds: 5,
                 body_format: BodyFormat::Query,
             }),
+            fallback: Vec::new(),
         };
 
-        let service = TtsService::new(config);
-        let path = service.cache_path(&service.cache_key("hello", Some("voice1")));
+        let service = test_service(config);
+        let driver = &service.config.driver;
+        let path = service.cache_path(driver, &service.cache_key(driver, "hello", Some("voice1")));
 
         // Pre-seed cache
         tokio::fs::create_dir_all(cache_dir.path()).await.unwrap();
@@ -248,6 +577,34 @@ mod tests {
         assert_eq!(result, path);
     }
 
+    #[test]
+    fn test_tts_cache_key_ignores_map_order() {
+        let config = limited_config(9999, Path::new("/tmp"), 1, None);
+        let service = test_service(config.clone());
+        let (mut forward, mut reverse) = (config.driver.clone(), config.driver.clone());
+        if let (TtsDriverConfig::Http(forward), TtsDriverConfig::Http(reverse)) =
+            (&mut forward, &mut reverse)
+        {
+            // Each HashMap has its own random iteration order
+            for i in 0..16 {
+                let j = 15 - i;
+                forward.headers.insert(format!("x-h{i}"), i.to_string());
+                forward.extra_params.insert(format!("p{i}"), i.to_string());
+                reverse.headers.insert(format!("x-h{j}"), j.to_string());
+                reverse.extra_params.insert(format!("p{j}"), j.to_string());
+            }
+        }
+
+        let key = service.cache_key(&forward, "hello", Some("voice1"));
+        assert_eq!(key.len(), 64);
+        assert_eq!(key, service.cache_key(&reverse, "hello", Some("voice1")));
+        assert_ne!(key, service.cache_key(&forward, "hello", None));
+        assert_ne!(
+            key,
+            service.cache_key(&config.driver, "hello", Some("voice1"))
+        );
+    }
+
     #[tokio::test]
     async fn test_tts_http_synthesize_get() {
         let wav = make_wav_bytes();
@@ -269,16 +626,17 @@ mod tests {
             ),
         );
 
-        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
-        let port = listener.local_addr().unwrap().port();
-        tokio::spawn(async move {
-            axum::serve(listener, app).await.ok();
-        });
+        let port = serve(app).await;
 
         let cache_dir = tempfile::tempdir().unwrap();
         let config = TtsConfig {
             cache_dir: cache_dir.path().to_string_lossy().to_string(),
             cache_ttl_seconds: 3600,
+            max_concurrency: None,
+            acquire_timeout_ms: None,
+            max_characters_per_call: None,
+            shared_cache: None,
+            warmup: false,
             driver: TtsDriverConfig::Http(HttpTtsConfig {
                 url: format!("http://127.0.0.1:{}/tts", port),
                 method: "GET".to_string(),
@@ -293,9 +651,10 @@ mod tests {
                 timeout_seconds: 5,
                 body_format: BodyFormat::Query,
             }),
+            fallback: Vec::new(),
         };
 
-        let service = TtsService::new(config);
+        let service = test_service(config);
         let path = service.synthesize("hello world", None).await.unwrap();
         assert!(
             path.contains("rustpbx_tts_cache") || path.contains(cache_dir.path().to_str().unwrap())
@@ -304,4 +663,334 @@ mod tests {
         let written = tokio::fs::read(&path).await.unwrap();
         assert_eq!(written, wav);
     }
+
+    fn limited_config(
+        port: u16,
+        cache_dir: &std::path::Path,
+        max_concurrency: usize,
+        acquire_timeout_ms: Option<u64>,
+    ) -> TtsConfig {
+        TtsConfig {
+            cache_dir: cache_dir.to_string_lossy().to_string(),
+            cache_ttl_seconds: 3600,
+            max_concurrency: Some(max_concurrency),
+            acquire_timeout_ms,
+            max_characters_per_call: None,
+            shared_cache: None,
+            warmup: false,
+            driver: TtsDriverConfig::Http(HttpTtsConfig {
+                url: format!("http://127.0.0.1:{}/tts", port),
+                method: "GET".to_string(),
+                param_name: "text".to_string(),
+                extra_params: HashMap::new(),
+                headers: HashMap::new(),
+                output_format: "wav".to_string(),
+                timeout_seconds: 5,
+                body_format: BodyFormat::Query,
+            }),
+            fallback: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tts_character_budget_rejects_over_limit() {
+        let wav = make_wav_bytes();
+        let app = Router::new().route("/tts", get(move || std::future::ready(wav.clone())));
+        let port = serve(app).await;
+
+        let cache_dir = tempfile::tempdir().unwrap();
+        let mut config = limited_config(port, cache_dir.path(), 4, None);
+        config.max_characters_per_call = Some(15);
+        let service = test_service(config);
+
+        service.synthesize("hello world", None).await.unwrap();
+        assert_eq!(service.characters_used(), 11);
+
+        let err = service.synthesize("over budget", None).await.unwrap_err();
+        let budget = err
+            .downcast_ref::<TtsBudgetExceeded>()
+            .expect("budget error");
+        assert_eq!((budget.used, budget.requested, budget.limit), (11, 11, 15));
+
+        // Cached prompts cost nothing and still play
+        service.synthesize("hello world", None).await.unwrap();
+        assert_eq!(service.characters_used(), 11);
+    }
+
+    #[tokio::test]
+    async fn test_tts_character_budget_reserved_before_synthesis() {
+        let app = slow_wav_router(Duration::from_millis(100)).route(
+            "/down",
+            get(|| async { axum::http::StatusCode::SERVICE_UNAVAILABLE }),
+        );
+        let port = serve(app).await;
+
+        let cache_dir = tempfile::tempdir().unwrap();
+        let mut config = limited_config(port, cache_dir.path(), 4, None);
+        config.max_characters_per_call = Some(15);
+        let service = test_service(config.clone());
+
+        // Both prompts fit on their own; only one may be in flight at a time.
+        let (first, second) = tokio::join!(
+            service.synthesize("hello world", None),
+            service.synthesize("hello again", None)
+        );
+        assert_eq!(first.is_ok() as u8 + second.is_ok() as u8, 1);
+        assert_eq!(service.characters_used(), 11);
+
+        // A failed request refunds its reservation.
+        if let TtsDriverConfig::Http(http) = &mut config.driver {
+            http.url = format!("http://127.0.0.1:{}/down", port);
+        }
+        let failing = test_service(config);
+        assert!(failing.synthesize("goodbye", None).await.is_err());
+        assert_eq!(failing.characters_used(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_tts_shared_cache_serves_other_gateways() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        let wav = make_wav_bytes();
+        let body = wav.clone();
+        let app = Router::new().route(
+            "/tts",
+            get(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                std::future::ready(body.clone())
+            }),
+        );
+        let port = serve(app).await;
+
+        let store = Arc::new(cache::MemoryCacheStore::new());
+        // Two gateways with separate local caches sharing one store
+        let first_dir = tempfile::tempdir().unwrap();
+        let first = test_service(limited_config(port, first_dir.path(), 4, None))
+            .with_cache_store(store.clone());
+        let second_dir = tempfile::tempdir().unwrap();
+        let second = test_service(limited_config(port, second_dir.path(), 4, None))
+            .with_cache_store(store.clone());
+
+        first.synthesize("welcome", None).await.unwrap();
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+        assert_eq!(store.len(), 1);
+
+        let path = second.synthesize("welcome", None).await.unwrap();
+        assert_eq!(
+            requests.load(Ordering::SeqCst),
+            1,
+            "served without re-synthesis"
+        );
+        assert_eq!(second.characters_used(), 0);
+        assert!(path.starts_with(second_dir.path().to_str().unwrap()));
+        assert_eq!(tokio::fs::read(&path).await.unwrap(), wav);
+    }
+
+    #[tokio::test]
+    async fn test_tts_services_reuse_pooled_connection() {
+        use axum::serve::ListenerExt;
+
+        let connections = Arc::new(AtomicUsize::new(0));
+        let counter = connections.clone();
+        let wav = make_wav_bytes();
+        let app = Router::new().route("/tts", get(move || std::future::ready(wav.clone())));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let listener = listener.tap_io(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.ok();
+        });
+
+        // Separate services, as separate calls would create
+        for text in ["first prompt", "second prompt", "third prompt"] {
+            let cache_dir = tempfile::tempdir().unwrap();
+            let service = test_service(limited_config(port, cache_dir.path(), 4, None));
+            service.synthesize(text, None).await.unwrap();
+        }
+
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_tts_warmup_primes_connection_for_first_prompt() {
+        use axum::serve::ListenerExt;
+
+        let connections = Arc::new(AtomicUsize::new(0));
+        let counter = connections.clone();
+        let warmups = Arc::new(AtomicUsize::new(0));
+        let heads = warmups.clone();
+        let wav = make_wav_bytes();
+        let app = Router::new().route(
+            "/tts",
+            get(move |method: axum::http::Method| {
+                if method == axum::http::Method::HEAD {
+                    heads.fetch_add(1, Ordering::SeqCst);
+                }
+                std::future::ready(wav.clone())
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let listener = listener.tap_io(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.ok();
+        });
+
+        let cache_dir = tempfile::tempdir().unwrap();
+        let mut config = limited_config(port, cache_dir.path(), 1, None);
+        config.warmup = true;
+        let service = TtsService::with_client(config, reqwest::Client::new())
+            .with_provider_limits(Default::default());
+
+        service.warmup().await;
+        assert_eq!(warmups.load(Ordering::SeqCst), 1);
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+
+        service.synthesize("first prompt", None).await.unwrap();
+        assert_eq!(warmups.load(Ordering::SeqCst), 1);
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_tts_provider_concurrency_limit() {
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let (in_flight_h, peak_h) = (in_flight.clone(), peak.clone());
+        let wav = make_wav_bytes();
+        let app = Router::new().route(
+            "/tts",
+            get(move || {
+                let (in_flight, peak, wav) = (in_flight_h.clone(), peak_h.clone(), wav.clone());
+                async move {
+                    let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(current, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    wav
+                }
+            }),
+        );
+        let port = serve(app).await;
+
+        let cache_dir = tempfile::tempdir().unwrap();
+        let limits = Arc::new(ProviderLimits::default());
+        let mut tasks = Vec::new();
+        for i in 0..6 {
+            // A fresh service per request mirrors per-call IVR instances.
+            let service = test_service(limited_config(port, cache_dir.path(), 2, None))
+                .with_provider_limits(limits.clone());
+            tasks.push(tokio::spawn(async move {
+                service.synthesize(&format!("prompt {}", i), None).await
+            }));
+        }
+        for task in tasks {
+            task.await.unwrap().unwrap();
+        }
+        assert!(peak.load(Ordering::SeqCst) <= 2);
+        assert_eq!(in_flight.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_tts_provider_limit_follows_config_change() {
+        let port = serve(slow_wav_router(Duration::from_millis(200))).await;
+
+        let cache_dir = tempfile::tempdir().unwrap();
+        let limits = Arc::new(ProviderLimits::default());
+        let service = |limit| {
+            test_service(limited_config(port, cache_dir.path(), limit, Some(0)))
+                .with_provider_limits(limits.clone())
+        };
+        let first = service(1);
+        let running = tokio::spawn(async move { first.synthesize("first", None).await });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let err = service(1).synthesize("second", None).await.unwrap_err();
+        assert!(err.to_string().contains("concurrency limit of 1"));
+        // A reloaded config with a new limit is not held to the old one.
+        service(2).synthesize("reloaded", None).await.unwrap();
+        running.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_tts_provider_concurrency_fail_fast() {
+        let port = serve(slow_wav_router(Duration::from_millis(200))).await;
+
+        let cache_dir = tempfile::tempdir().unwrap();
+        let limits = Arc::new(ProviderLimits::default());
+        let first = test_service(limited_config(port, cache_dir.path(), 1, Some(0)))
+            .with_provider_limits(limits.clone());
+        let second = test_service(limited_config(port, cache_dir.path(), 1, Some(0)))
+            .with_provider_limits(limits);
+        let running = tokio::spawn(async move { first.synthesize("first", None).await });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let err = second.synthesize("second", None).await.unwrap_err();
+        assert!(err.to_string().contains("concurrency limit"));
+        running.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_tts_http_timeout_covers_slow_provider() {
+        let app = Router::new().route(
+            "/tts",
+            get(|| async {
+                tokio::time::sleep(Duration::from_secs(10)).await;
+                "too late"
+            }),
+        );
+        let port = serve(app).await;
+
+        let cache_dir = tempfile::tempdir().unwrap();
+        let mut config = limited_config(port, cache_dir.path(), 1, None);
+        config.max_concurrency = None;
+        if let TtsDriverConfig::Http(http) = &mut config.driver {
+            http.timeout_seconds = 1;
+        }
+
+        let started = std::time::Instant::now();
+        let err = test_service(config)
+            .synthesize("hello", None)
+            .await
+            .unwrap_err();
+        assert!(err.downcast_ref::<TtsTimeout>().is_some());
+        assert!(started.elapsed() < Duration::from_secs(3));
+    }
+
+    #[tokio::test]
+    async fn test_tts_falls_back_to_secondary_provider() {
+        let wav = make_wav_bytes();
+        let wav_clone = wav.clone();
+        let app = Router::new()
+            .route(
+                "/primary",
+                get(|| async { (axum::http::StatusCode::SERVICE_UNAVAILABLE, "down") }),
+            )
+            .route(
+                "/tts",
+                get(move || {
+                    let wav = wav_clone.clone();
+                    async move { wav }
+                }),
+            );
+        let port = serve(app).await;
+
+        let cache_dir = tempfile::tempdir().unwrap();
+        let mut config = limited_config(port, cache_dir.path(), 1, None);
+        config.max_concurrency = None;
+        let secondary = config.driver.clone();
+        if let TtsDriverConfig::Http(http) = &mut config.driver {
+            http.url = format!("http://127.0.0.1:{}/primary", port);
+        }
+        config.fallback = vec![secondary];
+
+        let path = test_service(config)
+            .synthesize("hello", None)
+            .await
+            .unwrap();
+        assert_eq!(tokio::fs::read(&path).await.unwrap(), wav);
+    }
 }